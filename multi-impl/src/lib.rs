@@ -1,43 +1,160 @@
 use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Group, Spacing, TokenTree};
 use quote::quote;
-use syn::{braced, parenthesized, parse::Parse, parse_macro_input, punctuated::Punctuated, token::{Brace, Paren}, Expr, Token, TraitBound};
+use syn::{braced, parenthesized, parse::{Parse, ParseStream}, parse_macro_input, punctuated::Punctuated, Token, Type};
+
+/// One `impl` block, repeated for every target type. `#T` anywhere in it is replaced by the target type.
+struct ImplItem {
+    generics: proc_macro2::TokenStream,
+    impl_trait: proc_macro2::TokenStream,
+    where_clause: proc_macro2::TokenStream,
+    body: proc_macro2::TokenStream
+}
 
 struct Input {
-    impl_type: TraitBound,
-    _for: Token![for],
-    _paren: Paren,
-    types: Punctuated<Expr, Token![,]>,
-    _brace: Brace,
-    inner_tokens: proc_macro2::TokenStream
+    types: Punctuated<Type, Token![,]>,
+    items: Vec<ImplItem>
 }
 
+/// `multi_impl!([impl<...>] Trait for (A, B) [where ...] { ... })`, or to share one type list between several traits,
+/// `multi_impl!(for (A, B) { impl<...> Trait1 where ... { ... } impl Trait2 { ... } })`.
 impl Parse for Input {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let type_content;
-        let brace_content;
-        Ok(Input {
-            impl_type: input.parse()?,
-            _for: input.parse()?,
-            _paren: parenthesized!(type_content in input),
-            types: type_content.parse_terminated(Expr::parse, Token![,])?,
-            _brace: braced!(brace_content in input),
-            inner_tokens: brace_content.parse()?,
-        })
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![for]) {
+            input.parse::<Token![for]>()?;
+            let types = parse_types(input)?;
+
+            let content;
+            braced!(content in input);
+            let mut items = Vec::new();
+            while !content.is_empty() {
+                content.parse::<Token![impl]>()?;
+                let generics = parse_generics(&content)?;
+                let impl_trait = parse_until(&content, |input| input.is_empty() || input.peek(Token![where]) || is_body(input))?;
+                let (where_clause, body) = parse_where_and_body(&content)?;
+
+                items.push(ImplItem { generics, impl_trait, where_clause, body });
+            }
+
+            Ok(Input { types, items })
+        } else {
+            let generics = if input.peek(Token![impl]) {
+                input.parse::<Token![impl]>()?;
+                parse_generics(input)?
+            } else {
+                proc_macro2::TokenStream::new()
+            };
+            // `for<'a>` inside the trait path is a higher-ranked bound, not the start of the type list
+            let impl_trait = parse_until(input, |input| input.is_empty() || (input.peek(Token![for]) && !input.peek2(Token![<])))?;
+            input.parse::<Token![for]>()?;
+            let types = parse_types(input)?;
+            let (where_clause, body) = parse_where_and_body(input)?;
+
+            Ok(Input { types, items: vec![ImplItem { generics, impl_trait, where_clause, body }] })
+        }
     }
 }
 
+fn is_body(input: ParseStream) -> bool {
+    input.peek(syn::token::Brace)
+}
+
+fn parse_types(input: ParseStream) -> syn::Result<Punctuated<Type, Token![,]>> {
+    let type_content;
+    parenthesized!(type_content in input);
+
+    type_content.parse_terminated(Type::parse, Token![,])
+}
+
+/// Collects token trees up to (but not including) the point where `stop` returns true.
+fn parse_until(input: ParseStream, stop: impl Fn(ParseStream) -> bool) -> syn::Result<proc_macro2::TokenStream> {
+    let mut tokens = proc_macro2::TokenStream::new();
+    while !stop(input) {
+        tokens.extend(std::iter::once(input.parse::<TokenTree>()?));
+    }
+
+    Ok(tokens)
+}
+
+/// Parses `<...>` after `impl`, tracking nesting by hand since the bounds may contain `#T`.
+fn parse_generics(input: ParseStream) -> syn::Result<proc_macro2::TokenStream> {
+    let mut tokens = proc_macro2::TokenStream::new();
+    if !input.peek(Token![<]) {
+        return Ok(tokens);
+    }
+
+    let mut depth = 0;
+    let mut previous_joint_dash = false;
+    loop {
+        let tt: TokenTree = input.parse()?;
+        if let TokenTree::Punct(p) = &tt {
+            match p.as_char() {
+                '<' => depth += 1,
+                // `->` inside `Fn(...) -> T` bounds is not a closing bracket
+                '>' if !previous_joint_dash => depth -= 1,
+                _ => {}
+            }
+            previous_joint_dash = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+        } else {
+            previous_joint_dash = false;
+        }
+        tokens.extend(std::iter::once(tt));
+
+        if depth == 0 {
+            return Ok(tokens);
+        }
+    }
+}
+
+fn parse_where_and_body(input: ParseStream) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let where_clause = if input.peek(Token![where]) {
+        parse_until(input, |input| input.is_empty() || is_body(input))?
+    } else {
+        proc_macro2::TokenStream::new()
+    };
+
+    let brace_content;
+    braced!(brace_content in input);
+
+    Ok((where_clause, brace_content.parse()?))
+}
+
+fn substitute(tokens: proc_macro2::TokenStream, target: &Type) -> proc_macro2::TokenStream {
+    let mut output = proc_macro2::TokenStream::new();
+    let mut iter = tokens.into_iter().peekable();
+
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Punct(p) if p.as_char() == '#' && matches!(iter.peek(), Some(TokenTree::Ident(i)) if i == "T") => {
+                iter.next();
+                output.extend(std::iter::once(TokenTree::Group(Group::new(Delimiter::None, quote!(#target)))));
+            },
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), substitute(g.stream(), target));
+                group.set_span(g.span());
+                output.extend(std::iter::once(TokenTree::Group(group)));
+            },
+            tt => output.extend(std::iter::once(tt))
+        }
+    }
+
+    output
+}
+
 #[proc_macro]
 pub fn multi_impl(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as Input);
 
-    let impl_type = input.impl_type;
-    let body = input.inner_tokens;
+    input.items.iter().flat_map(|item| input.types.iter().map(move |type_| {
+        let generics = substitute(item.generics.clone(), type_);
+        let impl_trait = substitute(item.impl_trait.clone(), type_);
+        let where_clause = substitute(item.where_clause.clone(), type_);
+        let body = substitute(item.body.clone(), type_);
 
-    input.types.into_iter().map(|type_| {
         quote! {
-            impl #impl_type for #type_ {
+            impl #generics #impl_trait for #type_ #where_clause {
                 #body
             }
         }
-    }).collect::<proc_macro2::TokenStream>().into()
-}
\ No newline at end of file
+    })).collect::<proc_macro2::TokenStream>().into()
+}
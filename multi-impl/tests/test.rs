@@ -1,3 +1,8 @@
+// `AsFloat` takes `self` by value and casts `f32` to itself on purpose, to cover every numeric type
+#![allow(clippy::wrong_self_convention, clippy::unnecessary_cast)]
+
+use std::ops::{Add, Neg};
+
 use multi_impl::multi_impl;
 
 trait AsFloat {
@@ -15,4 +20,118 @@ fn test() {
     let float = 5.as_float();
 
     assert_eq!(float, 5.0);
-}
\ No newline at end of file
+}
+
+trait Name {
+    fn name() -> &'static str;
+}
+
+multi_impl!(Name for (u8, [u16; 2], Option<(i8, i16)>) {
+    fn name() -> &'static str {
+        std::any::type_name::<#T>()
+    }
+});
+
+#[test]
+fn placeholder() {
+    assert_eq!(u8::name(), "u8");
+    assert_eq!(<[u16; 2]>::name(), "[u16; 2]");
+    assert_eq!(<Option<(i8, i16)>>::name(), std::any::type_name::<Option<(i8, i16)>>());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Wrapper<T>(T);
+
+trait Doubled {
+    type Output;
+
+    fn doubled(self) -> Self::Output;
+}
+
+multi_impl!(impl<T: Copy + Add<Output = T>> Doubled for (Wrapper<T>, (T, T)) where #T: Pair<T> {
+    type Output = #T;
+
+    fn doubled(self) -> #T {
+        let (a, b) = self.parts();
+        <#T>::from_parts(a + a, b + b)
+    }
+});
+
+trait Pair<T> {
+    fn parts(self) -> (T, T);
+    fn from_parts(a: T, b: T) -> Self;
+}
+
+impl<T: Copy> Pair<T> for Wrapper<T> {
+    fn parts(self) -> (T, T) {
+        (self.0, self.0)
+    }
+
+    fn from_parts(a: T, _: T) -> Self {
+        Wrapper(a)
+    }
+}
+
+impl<T: Copy> Pair<T> for (T, T) {
+    fn parts(self) -> (T, T) {
+        self
+    }
+
+    fn from_parts(a: T, b: T) -> Self {
+        (a, b)
+    }
+}
+
+#[test]
+fn generics_and_where() {
+    assert_eq!(Wrapper(2).doubled(), Wrapper(4));
+    assert_eq!((1.5, -3.0).doubled(), (3.0, -6.0));
+}
+
+multi_impl!(for (Wrapper<i32>, Wrapper<f64>) {
+    impl Neg {
+        type Output = #T;
+
+        fn neg(self) -> #T {
+            Wrapper(-self.0)
+        }
+    }
+
+    impl<R> Add<R> where R: Into<#T> {
+        type Output = Self;
+
+        fn add(self, rhs: R) -> Self {
+            Wrapper(self.0 + rhs.into().0)
+        }
+    }
+});
+
+impl From<i32> for Wrapper<i32> {
+    fn from(value: i32) -> Self {
+        Wrapper(value)
+    }
+}
+
+#[test]
+fn multiple_traits() {
+    assert_eq!(-Wrapper(3), Wrapper(-3));
+    assert_eq!(-Wrapper(0.5), Wrapper(-0.5));
+    assert_eq!(Wrapper(3) + 4, Wrapper(7));
+    assert_eq!(Wrapper(0.5) + Wrapper(0.25), Wrapper(0.75));
+}
+
+trait Visit<F> {
+    fn visit(self, f: F) -> u32;
+}
+
+multi_impl!(Visit<for<'a> fn(&'a #T) -> u32> for (u8, u16) {
+    fn visit(self, f: for<'a> fn(&'a #T) -> u32) -> u32 {
+        f(&self)
+    }
+});
+
+#[test]
+fn higher_ranked_trait() {
+    assert_eq!(5u8.visit(|x| u32::from(*x) + 1), 6);
+    assert_eq!(300u16.visit(|x| u32::from(*x) * 2), 600);
+}
@@ -0,0 +1,223 @@
+//! Approximate equality for scalars, vectors and matrices.
+//!
+//! [`ApproxEq`], [`assert_approx_eq!`](crate::assert_approx_eq), [`epsilonEqual`](crate::functions::epsilon::epsilonEqual)
+//! and the matrix predicates compare through a [`Tolerance`], so they agree on what "close" means. The `approx` trait
+//! impls, behind the `approx` feature, delegate to nalgebra's and use that crate's semantics instead.
+
+use std::fmt::Debug;
+
+use crate::{
+    matrices::{Mat2, Mat3, Mat4},
+    vectors::{Vec2, Vec3, Vec4},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance {
+    /// The components differ by at most this much.
+    Absolute(f32),
+    /// The components differ by at most `max_relative` times the larger magnitude, or by at most `epsilon`, which
+    /// handles values near zero.
+    Relative { epsilon: f32, max_relative: f32 },
+    /// The components are at most `max_ulps` representable floats apart, or differ by at most `epsilon`.
+    Ulps { epsilon: f32, max_ulps: u32 },
+}
+
+impl Tolerance {
+    /// Used by [`ApproxEq::approx_eq`] and `assert_approx_eq!` when no tolerance is given.
+    pub const DEFAULT: Tolerance = Tolerance::Relative { epsilon: 1e-5, max_relative: 1e-5 };
+
+    /// Whether `a` and `b` are equal within this tolerance.
+    pub fn close(self, a: f32, b: f32) -> bool {
+        // Also handles infinities of the same sign
+        if a == b {
+            return true;
+        }
+
+        let difference = (a - b).abs();
+        match self {
+            Tolerance::Absolute(epsilon) => difference <= epsilon,
+            Tolerance::Relative { epsilon, max_relative } => {
+                if a.is_infinite() || b.is_infinite() {
+                    return false;
+                }
+
+                difference <= epsilon || difference <= a.abs().max(b.abs()) * max_relative
+            },
+            Tolerance::Ulps { epsilon, max_ulps } => {
+                if difference <= epsilon {
+                    return true;
+                }
+                if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+                    return false;
+                }
+
+                (a.to_bits() as i64 - b.to_bits() as i64).unsigned_abs() <= max_ulps as u64
+            },
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::DEFAULT
+    }
+}
+
+/// Component-wise approximate equality. Matrices are compared column by column.
+pub trait ApproxEq {
+    fn approx_eq_with(&self, other: &Self, tolerance: Tolerance) -> bool;
+
+    /// The components that are not equal within `tolerance`, as `(label, self, other)`. Used by `assert_approx_eq!`.
+    fn approx_mismatches(&self, other: &Self, tolerance: Tolerance) -> Vec<(String, f32, f32)>;
+
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_with(other, Tolerance::DEFAULT)
+    }
+
+    fn approx_eq_abs(&self, other: &Self, epsilon: f32) -> bool {
+        self.approx_eq_with(other, Tolerance::Absolute(epsilon))
+    }
+
+    fn approx_eq_relative(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.approx_eq_with(other, Tolerance::Relative { epsilon, max_relative })
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.approx_eq_with(other, Tolerance::Ulps { epsilon, max_ulps })
+    }
+}
+
+impl ApproxEq for f32 {
+    fn approx_eq_with(&self, other: &Self, tolerance: Tolerance) -> bool {
+        tolerance.close(*self, *other)
+    }
+
+    fn approx_mismatches(&self, other: &Self, tolerance: Tolerance) -> Vec<(String, f32, f32)> {
+        if tolerance.close(*self, *other) {
+            Vec::new()
+        } else {
+            vec![(String::new(), *self, *other)]
+        }
+    }
+}
+
+macro_rules! approx_eq_impl {
+    ($t:ty, $n:literal, $label:expr) => {
+        impl ApproxEq for $t {
+            fn approx_eq_with(&self, other: &Self, tolerance: Tolerance) -> bool {
+                self.0.iter().zip(other.0.iter()).all(|(a, b)| tolerance.close(*a, *b))
+            }
+
+            fn approx_mismatches(&self, other: &Self, tolerance: Tolerance) -> Vec<(String, f32, f32)> {
+                self.0.iter().zip(other.0.iter()).enumerate()
+                    .filter(|(_, (a, b))| !tolerance.close(**a, **b))
+                    .map(|(i, (a, b))| ($label(i / $n, i % $n), *a, *b))
+                    .collect()
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl approx::AbsDiffEq for $t {
+            type Epsilon = f32;
+
+            fn default_epsilon() -> f32 {
+                f32::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                self.0.abs_diff_eq(&other.0, epsilon)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl approx::RelativeEq for $t {
+            fn default_max_relative() -> f32 {
+                f32::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                self.0.relative_eq(&other.0, epsilon, max_relative)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl approx::UlpsEq for $t {
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            }
+        }
+    };
+    (vector $t:ty, $n:literal) => {
+        approx_eq_impl!($t, $n, |_, i| format!("[{}]", i));
+
+        impl $t {
+            /// Whether the length is 1 within `tolerance`.
+            pub fn is_normalized(&self, tolerance: Tolerance) -> bool {
+                tolerance.close(self.0.norm(), 1.0)
+            }
+        }
+    };
+    (matrix $t:ty, $n:literal) => {
+        approx_eq_impl!($t, $n, |c, r| format!("[{}][{}]", c, r));
+
+        impl $t {
+            pub fn is_identity(&self, tolerance: Tolerance) -> bool {
+                self.approx_eq_with(&<$t>::IDENTITY, tolerance)
+            }
+
+            /// Whether the columns are orthonormal, so that the transpose is the inverse.
+            pub fn is_orthogonal(&self, tolerance: Tolerance) -> bool {
+                Self(self.0.transpose() * self.0).is_identity(tolerance)
+            }
+
+            /// Whether every column has length 1 within `tolerance`.
+            pub fn is_normalized(&self, tolerance: Tolerance) -> bool {
+                self.0.column_iter().all(|c| tolerance.close(c.norm(), 1.0))
+            }
+        }
+    };
+}
+
+approx_eq_impl!(vector Vec2, 2);
+approx_eq_impl!(vector Vec3, 3);
+approx_eq_impl!(vector Vec4, 4);
+approx_eq_impl!(matrix Mat2, 2);
+approx_eq_impl!(matrix Mat3, 3);
+approx_eq_impl!(matrix Mat4, 4);
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_approx_eq_failed<T: Debug>(left: &T, right: &T, tolerance: Tolerance, mismatches: &[(String, f32, f32)]) -> ! {
+    let diff: Vec<String> = mismatches.iter()
+        .map(|(label, a, b)| format!("    {}: {:?} vs {:?} (difference {:?})", label, a, b, (a - b).abs()))
+        .collect();
+
+    panic!(
+        "assertion `left ≈ right` failed with {:?}\n  left: {:?}\n right: {:?}\n  diff:\n{}",
+        tolerance, left, right, diff.join("\n")
+    )
+}
+
+/// Like `assert_eq!`, but compares with [`ApproxEq`]. The tolerance defaults to [`Tolerance::DEFAULT`].
+///
+/// On failure, lists each component that differs.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, $crate::Tolerance::DEFAULT)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                let mismatches = $crate::ApproxEq::approx_mismatches(left, right, tolerance);
+                if !mismatches.is_empty() {
+                    $crate::__assert_approx_eq_failed(left, right, tolerance, &mismatches);
+                }
+            }
+        }
+    };
+}
@@ -5,8 +5,8 @@ pub trait ElementWise<const A: usize, const B: usize>: Copy + Clone {
 }
 
 impl<const R: usize, const C: usize, T: InnerMatrix<R, C> + Copy + Clone> ElementWise<R, C> for T {
-    fn operate<F: FnMut(&mut f32)>(mut self, mut f: F) -> T {
-        self.get_inner_matrix_mut().iter_mut().for_each(|el| f(el));
+    fn operate<F: FnMut(&mut f32)>(mut self, f: F) -> T {
+        self.get_inner_matrix_mut().iter_mut().for_each(f);
 
         self
     }
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlTypesError {
+    /// The matrix has no inverse, or one too large to represent.
+    SingularMatrix,
+    /// The matrix has an infinite or NaN component, so it has no meaningful inverse.
+    NonFiniteMatrix,
+    /// The vector has zero length, so it has no direction.
+    ZeroLengthVector,
+    /// The vector has an infinite or NaN component, so it has no meaningful direction.
+    NonFiniteVector,
+    /// A slice had the wrong number of components for the type it was converted to.
+    WrongLength { expected: usize, found: usize },
+    /// A GLSL literal couldn't be parsed. `position` is the byte offset of the problem.
+    InvalidSyntax { position: usize },
+    /// A GLSL literal constructs a different type, like `vec3(...)` parsed as a `Vec4`.
+    WrongType { expected: &'static str },
+    /// A flat slice can't be split evenly into values of `components` components each.
+    NotAMultiple { components: usize, found: usize },
+}
+
+impl Display for GlTypesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GlTypesError::SingularMatrix => write!(f, "matrix is singular and can't be inverted"),
+            GlTypesError::NonFiniteMatrix => write!(f, "matrix has a non-finite component and can't be inverted"),
+            GlTypesError::ZeroLengthVector => write!(f, "vector has zero length and can't be normalized"),
+            GlTypesError::NonFiniteVector => write!(f, "vector has a non-finite component and can't be normalized"),
+            GlTypesError::WrongLength { expected, found } => write!(f, "expected {} components, found {}", expected, found),
+            GlTypesError::InvalidSyntax { position } => write!(f, "invalid GLSL literal at byte {}", position),
+            GlTypesError::WrongType { expected } => write!(f, "expected a `{}(...)` literal", expected),
+            GlTypesError::NotAMultiple { components, found } => write!(f, "expected a multiple of {} components, found {}", components, found),
+        }
+    }
+}
+
+impl std::error::Error for GlTypesError {}
@@ -1,24 +1,36 @@
 use std::f32::consts::PI;
 
-use crate::ElementWise;
+use crate::{check_finite, ElementWise};
 
 pub fn radians<const R: usize, const C:  usize, T: ElementWise<R, C>>(degrees: T) -> T {
     const RATIO: f32 = PI / 180.0;
 
-    degrees.operate(|el| *el *= RATIO)
+    let result = degrees.operate(|el| *el *= RATIO);
+    check_finite!("radians", crate::nan_checks::elements(result), crate::nan_checks::elements(degrees));
+
+    result
 }
 
 pub fn degrees<const R: usize, const C:  usize, T: ElementWise<R, C>>(radians: T) -> T {
     const RATIO: f32 = 180.0 / PI;
 
-    radians.operate(|el| *el *= RATIO)
+    let result = radians.operate(|el| *el *= RATIO);
+    check_finite!("degrees", crate::nan_checks::elements(result), crate::nan_checks::elements(radians));
+
+    result
 }
 
 pub fn sin<const R: usize, const C:  usize, T: ElementWise<R, C>>(angle: T) -> T {
-    angle.operate(|el| *el = el.sin())
+    let result = angle.operate(|el| *el = el.sin());
+    check_finite!("sin", crate::nan_checks::elements(result), crate::nan_checks::elements(angle));
+
+    result
 }
 
 pub fn cos<const R: usize, const C:  usize, T: ElementWise<R, C>>(angle: T) -> T {
-    angle.operate(|el| *el = el.cos())
+    let result = angle.operate(|el| *el = el.cos());
+    check_finite!("cos", crate::nan_checks::elements(result), crate::nan_checks::elements(angle));
+
+    result
 }
 
@@ -0,0 +1,18 @@
+#![allow(non_snake_case)]
+
+//! GLSL-style per-component comparisons, in the style of glm's `gtc/epsilon`.
+
+use crate::{approx_eq::Tolerance, vectors::VecN};
+
+/// Whether each pair of components differs by at most `epsilon`.
+pub fn epsilonEqual<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R, y: R, epsilon: f32) -> [bool; N] {
+    let a = x.as_ref().as_slice();
+    let b = y.as_ref().as_slice();
+
+    std::array::from_fn(|i| Tolerance::Absolute(epsilon).close(a[i], b[i]))
+}
+
+/// Whether each pair of components differs by more than `epsilon`.
+pub fn epsilonNotEqual<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R, y: R, epsilon: f32) -> [bool; N] {
+    epsilonEqual(x, y, epsilon).map(|eq| !eq)
+}
@@ -1,13 +1,16 @@
 #![allow(non_snake_case)]
 
-use crate::vectors::VecN;
+use crate::{check_finite, vectors::VecN, GlTypesError};
 
 pub fn length<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R) -> f32 {
     let mut sum = 0.0;
     let mat = x.as_ref().get_inner_matrix();
     mat.iter().for_each(|el| sum += el * el);
 
-    sum.sqrt()
+    let result = sum.sqrt();
+    check_finite!("length", result, mat);
+
+    result
 }
 
 pub fn distance<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R, y: R) -> f32 {
@@ -17,57 +20,93 @@ pub fn distance<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R, y: R) -> f32 {
     let delta = b - a;
     let delta = V::make(delta);
     
-    length(&delta)
+    let result = length(&delta);
+    check_finite!("distance", result, a, b);
+
+    result
 }
 
 pub fn dot<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R, y: R) -> f32 {
     let a = x.as_ref().get_inner_matrix();
     let b = y.as_ref().get_inner_matrix();
 
-    a.dot(&b)
+    let result = a.dot(b);
+    check_finite!("dot", result, a, b);
+
+    result
 }
 
 pub fn cross<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R, y: R) -> V {
     let a = x.as_ref().get_inner_matrix();
     let b = y.as_ref().get_inner_matrix();
 
-    V::make(a.cross(&b))
+    let result = a.cross(b);
+    check_finite!("cross", result, a, b);
+
+    V::make(result)
 }
 
 pub fn normalize<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R) -> V {
     let v = x.as_ref().get_inner_matrix();
-    V::make(v.normalize())
+    let result = v.normalize();
+    check_finite!("normalize", result, v);
+
+    V::make(result)
+}
+
+/// Like [`normalize`], but reports a vector with no direction instead of returning NaN.
+///
+/// The vector is scaled by its largest component first, so huge or tiny vectors whose norm over- or underflows still normalize.
+pub fn try_normalize<const N: usize, V: VecN<N>, R: AsRef<V>>(x: R) -> Result<V, GlTypesError> {
+    let v = x.as_ref().get_inner_matrix();
+    if v.iter().any(|c| !c.is_finite()) {
+        return Err(GlTypesError::NonFiniteVector);
+    }
+
+    let max = v.amax();
+    if max == 0.0 {
+        return Err(GlTypesError::ZeroLengthVector);
+    }
+
+    let scaled = v / max;
+
+    Ok(V::make(scaled / scaled.norm()))
 }
 
 pub fn faceForward<const N: usize, V: VecN<N>, R: AsRef<V>>(n: R, i: R) -> V {
     let n = n.as_ref().get_inner_matrix();
     let i = i.as_ref().get_inner_matrix();
     
-    let dot = n.dot(&i);
-    if dot < 0.0 {
-        V::make(*n)
-    } else {
-        V::make(-n)
-    }
+    let dot = n.dot(i);
+    let result = if dot < 0.0 { *n } else { -n };
+    check_finite!("faceForward", result, n, i);
+
+    V::make(result)
 }
 
 pub fn reflect<const N: usize, V: VecN<N>, R: AsRef<V>>(i: R, n: R) -> V {
     let i = i.as_ref().get_inner_matrix();
     let n = n.as_ref().get_inner_matrix();
 
-    V::make(i - 2.0 * n.dot(&i) * n)
+    let result = i - 2.0 * n.dot(i) * n;
+    check_finite!("reflect", result, i, n);
+
+    V::make(result)
 }
 
 pub fn refract<const N: usize, V: VecN<N>, R: AsRef<V>>(i: R, n: R, eta: f32) -> V {
     let i = i.as_ref().get_inner_matrix();
     let n = n.as_ref().get_inner_matrix();
 
-    let n_dot_i = n.dot(&i);
+    let n_dot_i = n.dot(i);
     let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
 
     if k < 0.0 {
         V::from_array([0.0; N])
     } else {
-        V::make(eta * i - (eta * n_dot_i + k.sqrt()) * n)
+        let result = eta * i - (eta * n_dot_i + k.sqrt()) * n;
+        check_finite!("refract", result, i, n, eta);
+
+        V::make(result)
     }
 }
\ No newline at end of file
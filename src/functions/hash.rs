@@ -0,0 +1,226 @@
+//! Integer hash functions from Jarzynski and Olano, "Hash Functions for GPU Rendering" (JCGT, 2020).
+//!
+//! All arithmetic wraps modulo 2^32 as it does in GLSL, so the results are bit-identical to the
+//! same functions running in a shader.
+
+use crate::{private::Seal, vectors::{UVec2, UVec3, UVec4, Vec2, Vec3, Vec4}};
+
+use super::integer::IntegerComponents;
+
+const PRIME32_2: u32 = 2246822519;
+const PRIME32_3: u32 = 3266489917;
+const PRIME32_4: u32 = 668265263;
+const PRIME32_5: u32 = 374761393;
+
+pub trait XxHash32: Seal + Copy {
+    fn xxhash32(self) -> u32;
+}
+
+pub trait UnitFloat: Seal + Copy {
+    type Output;
+
+    fn unit_float(self) -> Self::Output;
+}
+
+fn map<const N: usize, T: IntegerComponents<N, Scalar = u32>, F: FnMut(u32) -> u32>(v: T, f: F) -> T {
+    T::from_components(v.components().map(f))
+}
+
+fn pcg_scalar(v: u32) -> u32 {
+    let state = v.wrapping_mul(747796405).wrapping_add(2891336453);
+    let word = ((state >> ((state >> 28) + 4)) ^ state).wrapping_mul(277803737);
+
+    (word >> 22) ^ word
+}
+
+/// PCG hash (`pcg` in the paper), applied to each component.
+pub fn pcg<const N: usize, T: IntegerComponents<N, Scalar = u32>>(v: T) -> T {
+    map(v, pcg_scalar)
+}
+
+/// Two-dimensional PCG hash; every output component depends on every input component.
+pub fn pcg2d(v: UVec2) -> UVec2 {
+    let v = v * 1664525 + 1013904223;
+    let [mut x, mut y] = v.as_array();
+
+    x = x.wrapping_add(y.wrapping_mul(1664525));
+    y = y.wrapping_add(x.wrapping_mul(1664525));
+
+    x ^= x >> 16;
+    y ^= y >> 16;
+
+    x = x.wrapping_add(y.wrapping_mul(1664525));
+    y = y.wrapping_add(x.wrapping_mul(1664525));
+
+    x ^= x >> 16;
+    y ^= y >> 16;
+
+    UVec2::from_array([x, y])
+}
+
+/// Three-dimensional PCG hash; every output component depends on every input component.
+pub fn pcg3d(v: UVec3) -> UVec3 {
+    let v = v * 1664525 + 1013904223;
+    let [mut x, mut y, mut z] = v.as_array();
+
+    x = x.wrapping_add(y.wrapping_mul(z));
+    y = y.wrapping_add(z.wrapping_mul(x));
+    z = z.wrapping_add(x.wrapping_mul(y));
+
+    x ^= x >> 16;
+    y ^= y >> 16;
+    z ^= z >> 16;
+
+    x = x.wrapping_add(y.wrapping_mul(z));
+    y = y.wrapping_add(z.wrapping_mul(x));
+    z = z.wrapping_add(x.wrapping_mul(y));
+
+    UVec3::from_array([x, y, z])
+}
+
+/// Four-dimensional PCG hash; every output component depends on every input component.
+pub fn pcg4d(v: UVec4) -> UVec4 {
+    let v = v * 1664525 + 1013904223;
+    let [mut x, mut y, mut z, mut w] = v.as_array();
+
+    x = x.wrapping_add(y.wrapping_mul(w));
+    y = y.wrapping_add(z.wrapping_mul(x));
+    z = z.wrapping_add(x.wrapping_mul(y));
+    w = w.wrapping_add(y.wrapping_mul(z));
+
+    x ^= x >> 16;
+    y ^= y >> 16;
+    z ^= z >> 16;
+    w ^= w >> 16;
+
+    x = x.wrapping_add(y.wrapping_mul(w));
+    y = y.wrapping_add(z.wrapping_mul(x));
+    z = z.wrapping_add(x.wrapping_mul(y));
+    w = w.wrapping_add(y.wrapping_mul(z));
+
+    UVec4::from_array([x, y, z, w])
+}
+
+/// The xxHash32-based hash from the paper, reducing a `u32` or `UVec2`/`UVec3`/`UVec4` to a single `u32`.
+pub fn xxhash32<T: XxHash32>(p: T) -> u32 {
+    p.xxhash32()
+}
+
+fn xxhash32_round(h32: u32) -> u32 {
+    h32.rotate_left(17).wrapping_mul(PRIME32_4)
+}
+
+fn xxhash32_avalanche(h32: u32) -> u32 {
+    let h32 = (h32 ^ (h32 >> 15)).wrapping_mul(PRIME32_2);
+    let h32 = (h32 ^ (h32 >> 13)).wrapping_mul(PRIME32_3);
+
+    h32 ^ (h32 >> 16)
+}
+
+impl XxHash32 for u32 {
+    fn xxhash32(self) -> u32 {
+        let h32 = self.wrapping_add(PRIME32_5);
+
+        xxhash32_avalanche(xxhash32_round(h32))
+    }
+}
+
+impl XxHash32 for UVec2 {
+    fn xxhash32(self) -> u32 {
+        let [x, y] = self.as_array();
+        let h32 = y.wrapping_add(PRIME32_5).wrapping_add(x.wrapping_mul(PRIME32_3));
+
+        xxhash32_avalanche(xxhash32_round(h32))
+    }
+}
+
+impl XxHash32 for UVec3 {
+    fn xxhash32(self) -> u32 {
+        let [x, y, z] = self.as_array();
+        let h32 = z.wrapping_add(PRIME32_5).wrapping_add(x.wrapping_mul(PRIME32_3));
+        let h32 = xxhash32_round(h32).wrapping_add(y.wrapping_mul(PRIME32_3));
+
+        xxhash32_avalanche(xxhash32_round(h32))
+    }
+}
+
+impl XxHash32 for UVec4 {
+    fn xxhash32(self) -> u32 {
+        let [x, y, z, w] = self.as_array();
+        let h32 = w.wrapping_add(PRIME32_5).wrapping_add(x.wrapping_mul(PRIME32_3));
+        let h32 = xxhash32_round(h32).wrapping_add(y.wrapping_mul(PRIME32_3));
+        let h32 = xxhash32_round(h32).wrapping_add(z.wrapping_mul(PRIME32_3));
+
+        xxhash32_avalanche(xxhash32_round(h32))
+    }
+}
+
+fn wang_hash_scalar(v: u32) -> u32 {
+    let v = (v ^ 61) ^ (v >> 16);
+    let v = v.wrapping_mul(9);
+    let v = v ^ (v >> 4);
+    let v = v.wrapping_mul(0x27d4eb2d);
+
+    v ^ (v >> 15)
+}
+
+/// Thomas Wang's integer hash, applied to each component.
+pub fn wang_hash<const N: usize, T: IntegerComponents<N, Scalar = u32>>(v: T) -> T {
+    map(v, wang_hash_scalar)
+}
+
+fn murmur3_fmix32_scalar(h: u32) -> u32 {
+    let h = (h ^ (h >> 16)).wrapping_mul(0x85ebca6b);
+    let h = (h ^ (h >> 13)).wrapping_mul(0xc2b2ae35);
+
+    h ^ (h >> 16)
+}
+
+/// The MurmurHash3 32-bit finalizer (`fmix32`), applied to each component.
+pub fn murmur3_fmix32<const N: usize, T: IntegerComponents<N, Scalar = u32>>(h: T) -> T {
+    map(h, murmur3_fmix32_scalar)
+}
+
+/// Maps each hash to a float uniformly distributed in [0, 1).
+///
+/// The top 23 bits become the mantissa of a float in [1, 2), which is then shifted down by 1. In GLSL this is
+/// `uintBitsToFloat(0x3f800000u | (h >> 9)) - 1.0`, and the result is exact on both sides.
+pub fn unit_float<H: UnitFloat>(h: H) -> H::Output {
+    h.unit_float()
+}
+
+fn unit_float_scalar(h: u32) -> f32 {
+    f32::from_bits(0x3f800000 | (h >> 9)) - 1.0
+}
+
+impl UnitFloat for u32 {
+    type Output = f32;
+
+    fn unit_float(self) -> f32 {
+        unit_float_scalar(self)
+    }
+}
+
+impl UnitFloat for UVec2 {
+    type Output = Vec2;
+
+    fn unit_float(self) -> Vec2 {
+        Vec2::_new(unit_float_scalar(self.x()), unit_float_scalar(self.y()))
+    }
+}
+
+impl UnitFloat for UVec3 {
+    type Output = Vec3;
+
+    fn unit_float(self) -> Vec3 {
+        Vec3::_new(unit_float_scalar(self.x()), unit_float_scalar(self.y()), unit_float_scalar(self.z()))
+    }
+}
+
+impl UnitFloat for UVec4 {
+    type Output = Vec4;
+
+    fn unit_float(self) -> Vec4 {
+        Vec4::_new(unit_float_scalar(self.x()), unit_float_scalar(self.y()), unit_float_scalar(self.z()), unit_float_scalar(self.w()))
+    }
+}
\ No newline at end of file
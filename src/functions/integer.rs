@@ -0,0 +1,240 @@
+#![allow(non_snake_case)]
+
+use crate::{private::Seal, vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4}};
+
+pub trait IntScalar: Seal + Copy {
+    fn bitfield_extract(self, offset: u32, bits: u32) -> Self;
+    fn bitfield_insert(self, insert: Self, offset: u32, bits: u32) -> Self;
+    fn bitfield_reverse(self) -> Self;
+    fn bit_count(self) -> i32;
+    fn find_lsb(self) -> i32;
+    fn find_msb(self) -> i32;
+}
+
+fn bit_mask(bits: u32) -> u32 {
+    if bits == 32 {
+        u32::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+impl IntScalar for u32 {
+    fn bitfield_extract(self, offset: u32, bits: u32) -> u32 {
+        if bits == 0 {
+            return 0;
+        }
+
+        (self >> offset) & bit_mask(bits)
+    }
+
+    fn bitfield_insert(self, insert: u32, offset: u32, bits: u32) -> u32 {
+        if bits == 0 {
+            return self;
+        }
+
+        let mask = bit_mask(bits) << offset;
+        (self & !mask) | ((insert << offset) & mask)
+    }
+
+    fn bitfield_reverse(self) -> u32 {
+        self.reverse_bits()
+    }
+
+    fn bit_count(self) -> i32 {
+        self.count_ones() as i32
+    }
+
+    fn find_lsb(self) -> i32 {
+        if self == 0 {
+            -1
+        } else {
+            self.trailing_zeros() as i32
+        }
+    }
+
+    fn find_msb(self) -> i32 {
+        if self == 0 {
+            -1
+        } else {
+            31 - self.leading_zeros() as i32
+        }
+    }
+}
+
+impl IntScalar for i32 {
+    fn bitfield_extract(self, offset: u32, bits: u32) -> i32 {
+        if bits == 0 {
+            return 0;
+        }
+
+        // Move the field to the top of the word so the arithmetic shift sign-extends it.
+        (self << (32 - offset - bits)) >> (32 - bits)
+    }
+
+    fn bitfield_insert(self, insert: i32, offset: u32, bits: u32) -> i32 {
+        (self as u32).bitfield_insert(insert as u32, offset, bits) as i32
+    }
+
+    fn bitfield_reverse(self) -> i32 {
+        self.reverse_bits()
+    }
+
+    fn bit_count(self) -> i32 {
+        self.count_ones() as i32
+    }
+
+    fn find_lsb(self) -> i32 {
+        (self as u32).find_lsb()
+    }
+
+    fn find_msb(self) -> i32 {
+        // For negative values GLSL returns the most significant zero bit.
+        if self < 0 {
+            (!self as u32).find_msb()
+        } else {
+            (self as u32).find_msb()
+        }
+    }
+}
+
+/// A scalar or vector of GLSL integers (`genIType` or `genUType`).
+pub trait IntegerComponents<const N: usize>: Seal + Copy {
+    type Scalar: IntScalar;
+    /// The signed type with the same number of components, used by `bitCount`, `findLSB` and `findMSB`.
+    type Signed: IntegerComponents<N, Scalar = i32>;
+
+    fn components(self) -> [Self::Scalar; N];
+    fn from_components(components: [Self::Scalar; N]) -> Self;
+}
+
+impl IntegerComponents<1> for i32 {
+    type Scalar = i32;
+    type Signed = i32;
+
+    fn components(self) -> [i32; 1] {
+        [self]
+    }
+
+    fn from_components(components: [i32; 1]) -> i32 {
+        components[0]
+    }
+}
+
+impl IntegerComponents<1> for u32 {
+    type Scalar = u32;
+    type Signed = i32;
+
+    fn components(self) -> [u32; 1] {
+        [self]
+    }
+
+    fn from_components(components: [u32; 1]) -> u32 {
+        components[0]
+    }
+}
+
+macro_rules! integer_components {
+    ($t:tt, $n:literal, $s:ty, $signed:tt) => {
+        impl IntegerComponents<$n> for $t {
+            type Scalar = $s;
+            type Signed = $signed;
+
+            fn components(self) -> [$s; $n] {
+                self.as_array()
+            }
+
+            fn from_components(components: [$s; $n]) -> $t {
+                $t::from_array(components)
+            }
+        }
+    };
+}
+
+integer_components!(IVec2, 2, i32, IVec2);
+integer_components!(IVec3, 3, i32, IVec3);
+integer_components!(IVec4, 4, i32, IVec4);
+integer_components!(UVec2, 2, u32, IVec2);
+integer_components!(UVec3, 3, u32, IVec3);
+integer_components!(UVec4, 4, u32, IVec4);
+
+fn map<const N: usize, T: IntegerComponents<N>, O: IntegerComponents<N>, F: FnMut(T::Scalar) -> O::Scalar>(x: T, f: F) -> O {
+    O::from_components(x.components().map(f))
+}
+
+fn zip<const N: usize, T: IntegerComponents<N>, F: FnMut(T::Scalar, T::Scalar) -> T::Scalar>(x: T, y: T, mut f: F) -> T {
+    let x = x.components();
+    let y = y.components();
+
+    T::from_components(std::array::from_fn(|i| f(x[i], y[i])))
+}
+
+fn check_bitfield(offset: i32, bits: i32) -> (u32, u32) {
+    debug_assert!(offset >= 0 && bits >= 0 && offset + bits <= 32, "bitfield offset {} and bits {} are out of range", offset, bits);
+
+    (offset as u32, bits as u32)
+}
+
+/// Adds two unsigned integers modulo 2^32, setting `carry` to 1 where the sum overflowed and 0 otherwise.
+pub fn uaddCarry<const N: usize, T: IntegerComponents<N, Scalar = u32>>(x: T, y: T, carry: &mut T) -> T {
+    *carry = zip(x, y, |a, b| a.overflowing_add(b).1 as u32);
+
+    zip(x, y, u32::wrapping_add)
+}
+
+/// Subtracts `y` from `x` modulo 2^32, setting `borrow` to 1 where `x < y` and 0 otherwise.
+pub fn usubBorrow<const N: usize, T: IntegerComponents<N, Scalar = u32>>(x: T, y: T, borrow: &mut T) -> T {
+    *borrow = zip(x, y, |a, b| (a < b) as u32);
+
+    zip(x, y, u32::wrapping_sub)
+}
+
+/// Multiplies two unsigned integers into a 64-bit result, split into its high (`msb`) and low (`lsb`) words.
+pub fn umulExtended<const N: usize, T: IntegerComponents<N, Scalar = u32>>(x: T, y: T, msb: &mut T, lsb: &mut T) {
+    *msb = zip(x, y, |a, b| ((a as u64 * b as u64) >> 32) as u32);
+    *lsb = zip(x, y, u32::wrapping_mul);
+}
+
+/// Multiplies two signed integers into a 64-bit result, split into its high (`msb`) and low (`lsb`) words.
+pub fn imulExtended<const N: usize, T: IntegerComponents<N, Scalar = i32>>(x: T, y: T, msb: &mut T, lsb: &mut T) {
+    *msb = zip(x, y, |a, b| ((a as i64 * b as i64) >> 32) as i32);
+    *lsb = zip(x, y, i32::wrapping_mul);
+}
+
+/// Extracts bits `[offset, offset + bits - 1]` from `value`, sign-extending the result for signed types.
+///
+/// Returns 0 if `bits` is 0. As in GLSL, the result is undefined if `offset + bits` is greater than 32.
+pub fn bitfieldExtract<const N: usize, T: IntegerComponents<N>>(value: T, offset: i32, bits: i32) -> T {
+    let (offset, bits) = check_bitfield(offset, bits);
+
+    map(value, |v: T::Scalar| v.bitfield_extract(offset, bits))
+}
+
+/// Replaces bits `[offset, offset + bits - 1]` of `base` with the low `bits` bits of `insert`.
+///
+/// Returns `base` if `bits` is 0. As in GLSL, the result is undefined if `offset + bits` is greater than 32.
+pub fn bitfieldInsert<const N: usize, T: IntegerComponents<N>>(base: T, insert: T, offset: i32, bits: i32) -> T {
+    let (offset, bits) = check_bitfield(offset, bits);
+
+    zip(base, insert, |b, i| b.bitfield_insert(i, offset, bits))
+}
+
+pub fn bitfieldReverse<const N: usize, T: IntegerComponents<N>>(value: T) -> T {
+    map(value, T::Scalar::bitfield_reverse)
+}
+
+pub fn bitCount<const N: usize, T: IntegerComponents<N>>(value: T) -> T::Signed {
+    map(value, T::Scalar::bit_count)
+}
+
+/// Returns the index of the least significant set bit, or -1 if `value` is 0.
+pub fn findLSB<const N: usize, T: IntegerComponents<N>>(value: T) -> T::Signed {
+    map(value, T::Scalar::find_lsb)
+}
+
+/// Returns the index of the most significant set bit, or -1 if `value` is 0.
+///
+/// For negative signed values this is the most significant zero bit, so -1 also gives -1.
+pub fn findMSB<const N: usize, T: IntegerComponents<N>>(value: T) -> T::Signed {
+    map(value, T::Scalar::find_msb)
+}
\ No newline at end of file
@@ -2,13 +2,16 @@
 
 use core::f32;
 
-use crate::{matrices::MatN, vectors::VecN};
+use crate::{check_finite, matrices::MatN, vectors::VecN, GlTypesError};
 
 pub fn matrixCompMult<const N: usize, M: MatN<N>, R: AsRef<M>>(x: R, y: R) -> M {
     let a = x.as_ref().get_inner_matrix();
     let b = y.as_ref().get_inner_matrix();
 
-    M::make(a.component_mul(&b))
+    let result = a.component_mul(b);
+    check_finite!("matrixCompMult", result, a, b);
+
+    M::make(result)
 }
 
 pub fn outerProduct<const N: usize, V: VecN<N>, M: MatN<N>, R: AsRef<V>>(x: R, y: R) -> M {
@@ -16,6 +19,8 @@ pub fn outerProduct<const N: usize, V: VecN<N>, M: MatN<N>, R: AsRef<V>>(x: R, y
     let b = y.as_ref().get_inner_matrix();
 
     let c = a * b.transpose();
+    check_finite!("outerProduct", c, a, b);
+
     M::make(c)
 }
 
@@ -27,14 +32,33 @@ pub fn transpose<const N: usize, M: MatN<N>, R: AsRef<M>>(mat: R) -> M {
 pub fn determinant<const N: usize, M: MatN<N>, R: AsRef<M>>(mat: R) -> f32
     where
         nalgebra::Const<N>: nalgebra::DimMin<nalgebra::Const<N>, Output = nalgebra::Const<N>> {
-    mat.as_ref().get_inner_matrix().determinant()
+    let mat = mat.as_ref().get_inner_matrix();
+    let result = mat.determinant();
+    check_finite!("determinant", result, mat);
+
+    result
 } 
 
 pub fn inverse<const N: usize, M: MatN<N>, R: AsRef<M>>(mat: R) -> M {
     let mat = mat.as_ref().get_inner_matrix();
 
+    let result = mat.try_inverse().unwrap_or(nalgebra::SMatrix::repeat(f32::NAN));
+    check_finite!("inverse", result, mat);
+
+    M::make(result)
+}
+
+/// Like [`inverse`], but reports a singular or non-finite matrix instead of returning one full of NaN.
+///
+/// A matrix so close to singular that its inverse overflows is reported as singular.
+pub fn try_inverse<const N: usize, M: MatN<N>, R: AsRef<M>>(mat: R) -> Result<M, GlTypesError> {
+    let mat = mat.as_ref().get_inner_matrix();
+    if mat.iter().any(|c| !c.is_finite()) {
+        return Err(GlTypesError::NonFiniteMatrix);
+    }
+
     match mat.try_inverse() {
-        Some(m) => M::make(m),
-        None => M::from_array([[f32::NAN; N]; N]),
+        Some(inverse) if inverse.iter().all(|c| c.is_finite()) => Ok(M::make(inverse)),
+        _ => Err(GlTypesError::SingularMatrix),
     }
-}
\ No newline at end of file
+}
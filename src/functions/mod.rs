@@ -1,5 +1,12 @@
 pub mod angle_trig;
+pub mod epsilon;
 pub mod geometric;
+pub mod hash;
+pub mod integer;
+pub mod noise;
 pub mod matrix;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod sampling;
 pub mod clip_space;
 pub mod transform;
\ No newline at end of file
@@ -0,0 +1,826 @@
+#![allow(non_snake_case)]
+// The constants are kept exactly as written in the reference GLSL so the two stay easy to compare.
+#![allow(clippy::excessive_precision)]
+
+//! Gradient and cellular noise, ported from the GLSL implementations by Ian McEwan (Ashima Arts)
+//! and Stefan Gustavson (<https://github.com/stegu/webgl-noise>, <https://github.com/stegu/psrdnoise>).
+
+use std::ops::{Add, Mul};
+
+use crate::{check_finite, private::Seal, vec2, vec3, vec4, vectors::{swizzles::Swizzle, Vec2, Vec3, Vec4, VecN}, ElementWise};
+
+use super::geometric::dot;
+
+pub trait GradientNoise: Seal + Copy {
+    fn snoise(self) -> f32;
+    fn cnoise(self) -> f32;
+    fn pnoise(self, rep: Self) -> f32;
+}
+
+pub trait CellularNoise: Seal + Copy {
+    fn cellular(self) -> Vec2;
+}
+
+pub trait PeriodicSimplexNoise: Seal + Copy {
+    fn psrdnoise(self, period: Self, alpha: f32, gradient: &mut Self) -> f32;
+}
+
+/// Simplex noise, in the range [-1, 1].
+pub fn snoise<V: GradientNoise>(v: V) -> f32 {
+    let result = v.snoise();
+    check_finite!("snoise", result);
+
+    result
+}
+
+/// Classic Perlin noise, in the range [-1, 1].
+pub fn cnoise<V: GradientNoise>(p: V) -> f32 {
+    let result = p.cnoise();
+    check_finite!("cnoise", result);
+
+    result
+}
+
+/// Classic Perlin noise that repeats every `rep` units along each axis.
+pub fn pnoise<V: GradientNoise>(p: V, rep: V) -> f32 {
+    let result = p.pnoise(rep);
+    check_finite!("pnoise", result);
+
+    result
+}
+
+/// Worley noise. Returns the distances to the closest (F1) and second closest (F2) feature points.
+pub fn cellular<V: CellularNoise>(p: V) -> Vec2 {
+    let result = p.cellular();
+    check_finite!("cellular", result.0);
+
+    result
+}
+
+/// Tiling simplex noise with rotating gradients.
+///
+/// Components of `period` that are zero or negative don't tile. `alpha` rotates the gradients,
+/// and the analytic derivative of the noise is written to `gradient`.
+pub fn psrdnoise<V: PeriodicSimplexNoise>(x: V, period: V, alpha: f32, gradient: &mut V) -> f32 {
+    let result = x.psrdnoise(period, alpha, gradient);
+    check_finite!("psrdnoise", result, alpha);
+
+    result
+}
+
+/// Fractal Brownian motion: sums `octaves` layers of `noise`, multiplying the frequency by `lacunarity`
+/// and the amplitude by `gain` for each layer. The first octave has amplitude 1.
+pub fn fbm<V: Copy + Mul<f32, Output = V>, F: Fn(V) -> f32>(p: V, octaves: u32, lacunarity: f32, gain: f32, noise: F) -> f32 {
+    let mut sum = 0.0;
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+
+    for _ in 0..octaves {
+        sum += amplitude * noise(p * frequency);
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+    check_finite!("fbm", sum, lacunarity, gain);
+
+    sum
+}
+
+impl GradientNoise for Vec2 {
+    fn snoise(self) -> f32 {
+        simplex2(self)
+    }
+
+    fn cnoise(self) -> f32 {
+        perlin2(self, None)
+    }
+
+    fn pnoise(self, rep: Vec2) -> f32 {
+        perlin2(self, Some(rep))
+    }
+}
+
+impl GradientNoise for Vec3 {
+    fn snoise(self) -> f32 {
+        simplex3(self)
+    }
+
+    fn cnoise(self) -> f32 {
+        perlin3(self, None)
+    }
+
+    fn pnoise(self, rep: Vec3) -> f32 {
+        perlin3(self, Some(rep))
+    }
+}
+
+impl GradientNoise for Vec4 {
+    fn snoise(self) -> f32 {
+        simplex4(self)
+    }
+
+    fn cnoise(self) -> f32 {
+        perlin4(self, None)
+    }
+
+    fn pnoise(self, rep: Vec4) -> f32 {
+        perlin4(self, Some(rep))
+    }
+}
+
+impl CellularNoise for Vec2 {
+    fn cellular(self) -> Vec2 {
+        cellular2(self)
+    }
+}
+
+impl CellularNoise for Vec3 {
+    fn cellular(self) -> Vec2 {
+        cellular3(self)
+    }
+}
+
+impl PeriodicSimplexNoise for Vec2 {
+    fn psrdnoise(self, period: Vec2, alpha: f32, gradient: &mut Vec2) -> f32 {
+        psrdnoise2(self, period, alpha, gradient)
+    }
+}
+
+impl PeriodicSimplexNoise for Vec3 {
+    fn psrdnoise(self, period: Vec3, alpha: f32, gradient: &mut Vec3) -> f32 {
+        psrdnoise3(self, period, alpha, gradient)
+    }
+}
+
+fn mod289(x: f32) -> f32 {
+    x - (x * (1.0 / 289.0)).floor() * 289.0
+}
+
+fn mod7(x: f32) -> f32 {
+    x - (x * (1.0 / 7.0)).floor() * 7.0
+}
+
+fn permute(x: f32) -> f32 {
+    mod289(((x * 34.0) + 10.0) * x)
+}
+
+fn taylor_inv_sqrt(r: f32) -> f32 {
+    1.79284291400159 - 0.85373472095314 * r
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn glsl_mod(x: f32, y: f32) -> f32 {
+    x - y * (x / y).floor()
+}
+
+fn step(edge: f32, x: f32) -> f32 {
+    if x < edge {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+fn mix<T: Mul<f32, Output = T> + Add<Output = T>>(x: T, y: T, a: f32) -> T {
+    x * (1.0 - a) + y * a
+}
+
+fn map<const N: usize, V: VecN<N> + Copy, F: Fn(f32) -> f32>(x: V, f: F) -> V {
+    x.operate(|el| *el = f(*el))
+}
+
+fn zip<const N: usize, V: VecN<N>, F: Fn(f32, f32) -> f32>(x: V, y: V, f: F) -> V {
+    let x = x.as_array();
+    let y = y.as_array();
+
+    V::from_array(std::array::from_fn(|i| f(x[i], y[i])))
+}
+
+fn floor<const N: usize, V: VecN<N> + Copy>(x: V) -> V {
+    map(x, f32::floor)
+}
+
+fn fract<const N: usize, V: VecN<N> + Copy>(x: V) -> V {
+    map(x, |a| a - a.floor())
+}
+
+fn abs<const N: usize, V: VecN<N> + Copy>(x: V) -> V {
+    map(x, f32::abs)
+}
+
+fn max<const N: usize, V: VecN<N> + Copy>(x: V, y: f32) -> V {
+    map(x, |a| a.max(y))
+}
+
+fn normalized<const N: usize, V: VecN<N> + Copy + Mul<f32, Output = V>>(g: V) -> V {
+    g * taylor_inv_sqrt(dot(g, g))
+}
+
+/// Returns the square roots of the two smallest squared distances.
+fn closest_two<I: IntoIterator<Item = f32>>(distances: I) -> Vec2 {
+    let (f1, f2) = distances.into_iter().fold((f32::INFINITY, f32::INFINITY), |(f1, f2), d| {
+        if d < f1 {
+            (d, f1)
+        } else {
+            (f1, f2.min(d))
+        }
+    });
+
+    vec2!(f1.sqrt(), f2.sqrt())
+}
+
+fn simplex2(v: Vec2) -> f32 {
+    let c = vec4!(
+        0.211324865405187,  // (3.0 - sqrt(3.0)) / 6.0
+        0.366025403784439,  // 0.5 * (sqrt(3.0) - 1.0)
+        -0.577350269189626, // -1.0 + 2.0 * C.x
+        0.024390243902439   // 1.0 / 41.0
+    );
+
+    // First corner
+    let i = floor(v + dot(v, c.swizzle([1, 1])));
+    let x0 = v - i + dot(i, c.swizzle([0, 0]));
+
+    // Other corners
+    let i1 = if x0.x() > x0.y() { vec2!(1.0, 0.0) } else { vec2!(0.0, 1.0) };
+    let x12 = x0.swizzle([0, 1, 0, 1]) + c.swizzle([0, 0, 2, 2]) - vec4!(i1, 0.0, 0.0);
+
+    // Permutations
+    let i = map(i, mod289);
+    let p = map(map(vec3!(0.0, i1.y(), 1.0) + i.y(), permute) + i.x() + vec3!(0.0, i1.x(), 1.0), permute);
+
+    let m = max(vec3!(0.5) - vec3!(dot(x0, x0), dot(x12.swizzle([0, 1]), x12.swizzle([0, 1])), dot(x12.swizzle([2, 3]), x12.swizzle([2, 3]))), 0.0);
+    let m = m * m;
+    let m = m * m;
+
+    // Gradients: 41 points uniformly over a line, mapped onto a diamond.
+    let x = fract(p * c.w()) * 2.0 - 1.0;
+    let h = abs(x) - 0.5;
+    let ox = floor(x + 0.5);
+    let a0 = x - ox;
+
+    // Normalise gradients implicitly by scaling m
+    let m = m * (vec3!(1.79284291400159) - (a0 * a0 + h * h) * 0.85373472095314);
+
+    let g = vec3!(a0.x() * x0.x() + h.x() * x0.y(), a0.swizzle([1, 2]) * x12.swizzle([0, 2]) + h.swizzle([1, 2]) * x12.swizzle([1, 3]));
+    130.0 * dot(m, g)
+}
+
+fn simplex3(v: Vec3) -> f32 {
+    let c = vec2!(1.0 / 6.0, 1.0 / 3.0);
+    let d = vec4!(0.0, 0.5, 1.0, 2.0);
+
+    // First corner
+    let i = floor(v + dot(v, c.swizzle([1, 1, 1])));
+    let x0 = v - i + dot(i, c.swizzle([0, 0, 0]));
+
+    // Other corners
+    let g = zip(x0.swizzle([1, 2, 0]), x0.swizzle([0, 1, 2]), step);
+    let l = vec3!(1.0) - g;
+    let i1 = zip(g.swizzle([0, 1, 2]), l.swizzle([2, 0, 1]), f32::min);
+    let i2 = zip(g.swizzle([0, 1, 2]), l.swizzle([2, 0, 1]), f32::max);
+
+    let x1 = x0 - i1 + c.swizzle([0, 0, 0]);
+    let x2 = x0 - i2 + c.swizzle([1, 1, 1]);
+    let x3 = x0 - d.swizzle([1, 1, 1]);
+
+    // Permutations
+    let i = map(i, mod289);
+    let p = map(vec4!(0.0, i1.z(), i2.z(), 1.0) + i.z(), permute);
+    let p = map(p + i.y() + vec4!(0.0, i1.y(), i2.y(), 1.0), permute);
+    let p = map(p + i.x() + vec4!(0.0, i1.x(), i2.x(), 1.0), permute);
+
+    // Gradients: 7x7 points over a square, mapped onto an octahedron.
+    let n_ = 0.142857142857; // 1.0 / 7.0
+    let ns = d.swizzle([3, 1, 2]) * n_ - d.swizzle([0, 2, 0]);
+
+    let j = p - floor(p * ns.z() * ns.z()) * 49.0;
+
+    let x_ = floor(j * ns.z());
+    let y_ = floor(j - x_ * 7.0);
+
+    let x = x_ * ns.x() + ns.swizzle([1, 1, 1, 1]);
+    let y = y_ * ns.x() + ns.swizzle([1, 1, 1, 1]);
+    let h = vec4!(1.0) - abs(x) - abs(y);
+
+    let b0 = vec4!(x.swizzle([0, 1]), y.swizzle([0, 1]));
+    let b1 = vec4!(x.swizzle([2, 3]), y.swizzle([2, 3]));
+
+    let s0 = floor(b0) * 2.0 + 1.0;
+    let s1 = floor(b1) * 2.0 + 1.0;
+    let sh = zip(h, Vec4::ZERO, step) * -1.0;
+
+    let a0 = b0.swizzle([0, 2, 1, 3]) + s0.swizzle([0, 2, 1, 3]) * sh.swizzle([0, 0, 1, 1]);
+    let a1 = b1.swizzle([0, 2, 1, 3]) + s1.swizzle([0, 2, 1, 3]) * sh.swizzle([2, 2, 3, 3]);
+
+    let p0 = normalized(vec3!(a0.swizzle([0, 1]), h.x()));
+    let p1 = normalized(vec3!(a0.swizzle([2, 3]), h.y()));
+    let p2 = normalized(vec3!(a1.swizzle([0, 1]), h.z()));
+    let p3 = normalized(vec3!(a1.swizzle([2, 3]), h.w()));
+
+    // Mix final noise value
+    let m = max(vec4!(0.5) - vec4!(dot(x0, x0), dot(x1, x1), dot(x2, x2), dot(x3, x3)), 0.0);
+    let m = m * m;
+    105.0 * dot(m * m, vec4!(dot(p0, x0), dot(p1, x1), dot(p2, x2), dot(p3, x3)))
+}
+
+fn grad4(j: f32, ip: Vec4) -> Vec4 {
+    let ones = vec4!(1.0, 1.0, 1.0, -1.0);
+
+    let p = floor(fract(vec3!(j) * ip.swizzle([0, 1, 2])) * 7.0) * ip.z() - 1.0;
+    let w = 1.5 - dot(abs(p), ones.swizzle([0, 1, 2]));
+    let s = vec4!(p, w).operate(|el| *el = if *el < 0.0 { 1.0 } else { 0.0 });
+
+    vec4!(p + (s.swizzle([0, 1, 2]) * 2.0 - 1.0) * s.w(), w)
+}
+
+fn simplex4(v: Vec4) -> f32 {
+    const F4: f32 = 0.309016994374947451; // (sqrt(5) - 1) / 4
+
+    let c = vec4!(
+        0.138196601125011,  // (5 - sqrt(5)) / 20, G4
+        0.276393202250021,  // 2 * G4
+        0.414589803375032,  // 3 * G4
+        -0.447213595499958  // -1 + 4 * G4
+    );
+
+    // First corner
+    let i = floor(v + dot(v, vec4!(F4)));
+    let x0 = v - i + dot(i, c.swizzle([0, 0, 0, 0]));
+
+    // Other corners: rank sorting to find the simplex the point is in.
+    let is_x = zip(x0.swizzle([1, 2, 3]), x0.swizzle([0, 0, 0]), step);
+    let is_yz = zip(x0.swizzle([2, 3, 3]), x0.swizzle([1, 1, 2]), step);
+    let i0 = vec4!(
+        is_x.x() + is_x.y() + is_x.z(),
+        (1.0 - is_x.x()) + (is_yz.x() + is_yz.y()),
+        (1.0 - is_x.y()) + (1.0 - is_yz.x()) + is_yz.z(),
+        (1.0 - is_x.z()) + (1.0 - is_yz.y()) + (1.0 - is_yz.z())
+    );
+
+    let i3 = map(i0, |a| a.clamp(0.0, 1.0));
+    let i2 = map(i0 - 1.0, |a| a.clamp(0.0, 1.0));
+    let i1 = map(i0 - 2.0, |a| a.clamp(0.0, 1.0));
+
+    let x1 = x0 - i1 + c.swizzle([0, 0, 0, 0]);
+    let x2 = x0 - i2 + c.swizzle([1, 1, 1, 1]);
+    let x3 = x0 - i3 + c.swizzle([2, 2, 2, 2]);
+    let x4 = x0 + c.swizzle([3, 3, 3, 3]);
+
+    // Permutations
+    let i = map(i, mod289);
+    let j0 = permute(permute(permute(permute(i.w()) + i.z()) + i.y()) + i.x());
+    let j1 = map(vec4!(i1.w(), i2.w(), i3.w(), 1.0) + i.w(), permute);
+    let j1 = map(j1 + i.z() + vec4!(i1.z(), i2.z(), i3.z(), 1.0), permute);
+    let j1 = map(j1 + i.y() + vec4!(i1.y(), i2.y(), i3.y(), 1.0), permute);
+    let j1 = map(j1 + i.x() + vec4!(i1.x(), i2.x(), i3.x(), 1.0), permute);
+
+    // Gradients: 7x7x6 points over a cube, mapped onto a 4-cross polytope.
+    let ip = vec4!(1.0 / 294.0, 1.0 / 49.0, 1.0 / 7.0, 0.0);
+
+    let p0 = normalized(grad4(j0, ip));
+    let p1 = normalized(grad4(j1.x(), ip));
+    let p2 = normalized(grad4(j1.y(), ip));
+    let p3 = normalized(grad4(j1.z(), ip));
+    let p4 = normalized(grad4(j1.w(), ip));
+
+    // Mix contributions from the five corners
+    let m0 = max(vec3!(0.57) - vec3!(dot(x0, x0), dot(x1, x1), dot(x2, x2)), 0.0);
+    let m1 = max(vec2!(0.57) - vec2!(dot(x3, x3), dot(x4, x4)), 0.0);
+    let m0 = m0 * m0;
+    let m1 = m1 * m1;
+
+    60.1 * (dot(m0 * m0, vec3!(dot(p0, x0), dot(p1, x1), dot(p2, x2))) + dot(m1 * m1, vec2!(dot(p3, x3), dot(p4, x4))))
+}
+
+fn perlin2(p: Vec2, rep: Option<Vec2>) -> f32 {
+    let pi = floor(p.swizzle([0, 1, 0, 1])) + vec4!(0.0, 0.0, 1.0, 1.0);
+    let pf = fract(p.swizzle([0, 1, 0, 1])) - vec4!(0.0, 0.0, 1.0, 1.0);
+    let pi = match rep {
+        Some(rep) => zip(pi, rep.swizzle([0, 1, 0, 1]), glsl_mod),
+        None => pi,
+    };
+    let pi = map(pi, mod289); // To avoid truncation effects in permutation
+
+    let ix = pi.swizzle([0, 2, 0, 2]);
+    let iy = pi.swizzle([1, 1, 3, 3]);
+    let fx = pf.swizzle([0, 2, 0, 2]);
+    let fy = pf.swizzle([1, 1, 3, 3]);
+
+    let i = map(map(ix, permute) + iy, permute);
+
+    let gx = fract(i * (1.0 / 41.0)) * 2.0 - 1.0;
+    let gy = abs(gx) - 0.5;
+    let tx = floor(gx + 0.5);
+    let gx = gx - tx;
+
+    let g00 = normalized(vec2!(gx.x(), gy.x()));
+    let g10 = normalized(vec2!(gx.y(), gy.y()));
+    let g01 = normalized(vec2!(gx.z(), gy.z()));
+    let g11 = normalized(vec2!(gx.w(), gy.w()));
+
+    let n00 = dot(g00, vec2!(fx.x(), fy.x()));
+    let n10 = dot(g10, vec2!(fx.y(), fy.y()));
+    let n01 = dot(g01, vec2!(fx.z(), fy.z()));
+    let n11 = dot(g11, vec2!(fx.w(), fy.w()));
+
+    let fade_xy = map(pf.swizzle([0, 1]), fade);
+    let n_x = mix(vec2!(n00, n01), vec2!(n10, n11), fade_xy.x());
+    let n_xy = mix(n_x.x(), n_x.y(), fade_xy.y());
+    2.3 * n_xy
+}
+
+/// Gradients for the four corners hashed in `ixy`, in the order x, y, z, w of `ixy`.
+fn perlin3_gradients(ixy: Vec4) -> [Vec3; 4] {
+    let gx = ixy * (1.0 / 7.0);
+    let gy = fract(floor(gx) * (1.0 / 7.0)) - 0.5;
+    let gx = fract(gx);
+    let gz = vec4!(0.5) - abs(gx) - abs(gy);
+    let sz = zip(gz, Vec4::ZERO, step);
+    let gx = gx - sz * (zip(Vec4::ZERO, gx, step) - 0.5);
+    let gy = gy - sz * (zip(Vec4::ZERO, gy, step) - 0.5);
+
+    let (gx, gy, gz) = (gx.as_array(), gy.as_array(), gz.as_array());
+    std::array::from_fn(|i| normalized(vec3!(gx[i], gy[i], gz[i])))
+}
+
+fn perlin3(p: Vec3, rep: Option<Vec3>) -> f32 {
+    let (pi0, pi1) = match rep {
+        Some(rep) => {
+            let pi0 = zip(floor(p), rep, glsl_mod);
+            (pi0, zip(pi0 + 1.0, rep, glsl_mod))
+        },
+        None => (floor(p), floor(p) + 1.0),
+    };
+    let pi0 = map(pi0, mod289);
+    let pi1 = map(pi1, mod289);
+    let pf0 = fract(p);
+    let pf1 = pf0 - 1.0;
+
+    let ix = vec4!(pi0.x(), pi1.x(), pi0.x(), pi1.x());
+    let iy = vec4!(pi0.swizzle([1, 1]), pi1.swizzle([1, 1]));
+    let iz0 = pi0.swizzle([2, 2, 2, 2]);
+    let iz1 = pi1.swizzle([2, 2, 2, 2]);
+
+    let ixy = map(map(ix, permute) + iy, permute);
+    let ixy0 = map(ixy + iz0, permute);
+    let ixy1 = map(ixy + iz1, permute);
+
+    let [g000, g100, g010, g110] = perlin3_gradients(ixy0);
+    let [g001, g101, g011, g111] = perlin3_gradients(ixy1);
+
+    let n000 = dot(g000, pf0);
+    let n100 = dot(g100, vec3!(pf1.x(), pf0.swizzle([1, 2])));
+    let n010 = dot(g010, vec3!(pf0.x(), pf1.y(), pf0.z()));
+    let n110 = dot(g110, vec3!(pf1.swizzle([0, 1]), pf0.z()));
+    let n001 = dot(g001, vec3!(pf0.swizzle([0, 1]), pf1.z()));
+    let n101 = dot(g101, vec3!(pf1.x(), pf0.y(), pf1.z()));
+    let n011 = dot(g011, vec3!(pf0.x(), pf1.swizzle([1, 2])));
+    let n111 = dot(g111, pf1);
+
+    let fade_xyz = map(pf0, fade);
+    let n_z = mix(vec4!(n000, n100, n010, n110), vec4!(n001, n101, n011, n111), fade_xyz.z());
+    let n_yz = mix(n_z.swizzle([0, 1]), n_z.swizzle([2, 3]), fade_xyz.y());
+    let n_xyz = mix(n_yz.x(), n_yz.y(), fade_xyz.x());
+    2.2 * n_xyz
+}
+
+/// Gradients for the four corners hashed in `ixy`, in the order x, y, z, w of `ixy`.
+fn perlin4_gradients(ixy: Vec4) -> [Vec4; 4] {
+    let gx = ixy * (1.0 / 7.0);
+    let gy = floor(gx) * (1.0 / 7.0);
+    let gz = floor(gy) * (1.0 / 6.0);
+    let gx = fract(gx) - 0.5;
+    let gy = fract(gy) - 0.5;
+    let gz = fract(gz) - 0.5;
+    let gw = vec4!(0.75) - abs(gx) - abs(gy) - abs(gz);
+    let sw = zip(gw, Vec4::ZERO, step);
+    let gx = gx - sw * (zip(Vec4::ZERO, gx, step) - 0.5);
+    let gy = gy - sw * (zip(Vec4::ZERO, gy, step) - 0.5);
+
+    let (gx, gy, gz, gw) = (gx.as_array(), gy.as_array(), gz.as_array(), gw.as_array());
+    std::array::from_fn(|i| normalized(vec4!(gx[i], gy[i], gz[i], gw[i])))
+}
+
+fn perlin4(p: Vec4, rep: Option<Vec4>) -> f32 {
+    let (pi0, pi1) = match rep {
+        Some(rep) => {
+            let pi0 = zip(floor(p), rep, glsl_mod);
+            (pi0, zip(pi0 + 1.0, rep, glsl_mod))
+        },
+        None => (floor(p), floor(p) + 1.0),
+    };
+    let pi0 = map(pi0, mod289);
+    let pi1 = map(pi1, mod289);
+    let pf0 = fract(p);
+    let pf1 = pf0 - 1.0;
+
+    let ix = vec4!(pi0.x(), pi1.x(), pi0.x(), pi1.x());
+    let iy = vec4!(pi0.swizzle([1, 1]), pi1.swizzle([1, 1]));
+    let iz0 = pi0.swizzle([2, 2, 2, 2]);
+    let iz1 = pi1.swizzle([2, 2, 2, 2]);
+    let iw0 = pi0.swizzle([3, 3, 3, 3]);
+    let iw1 = pi1.swizzle([3, 3, 3, 3]);
+
+    let ixy = map(map(ix, permute) + iy, permute);
+    let ixy0 = map(ixy + iz0, permute);
+    let ixy1 = map(ixy + iz1, permute);
+    let ixy00 = map(ixy0 + iw0, permute);
+    let ixy01 = map(ixy0 + iw1, permute);
+    let ixy10 = map(ixy1 + iw0, permute);
+    let ixy11 = map(ixy1 + iw1, permute);
+
+    let [g0000, g1000, g0100, g1100] = perlin4_gradients(ixy00);
+    let [g0001, g1001, g0101, g1101] = perlin4_gradients(ixy01);
+    let [g0010, g1010, g0110, g1110] = perlin4_gradients(ixy10);
+    let [g0011, g1011, g0111, g1111] = perlin4_gradients(ixy11);
+
+    let n0000 = dot(g0000, pf0);
+    let n1000 = dot(g1000, vec4!(pf1.x(), pf0.swizzle([1, 2, 3])));
+    let n0100 = dot(g0100, vec4!(pf0.x(), pf1.y(), pf0.swizzle([2, 3])));
+    let n1100 = dot(g1100, vec4!(pf1.swizzle([0, 1]), pf0.swizzle([2, 3])));
+    let n0010 = dot(g0010, vec4!(pf0.swizzle([0, 1]), pf1.z(), pf0.w()));
+    let n1010 = dot(g1010, vec4!(pf1.x(), pf0.y(), pf1.z(), pf0.w()));
+    let n0110 = dot(g0110, vec4!(pf0.x(), pf1.swizzle([1, 2]), pf0.w()));
+    let n1110 = dot(g1110, vec4!(pf1.swizzle([0, 1, 2]), pf0.w()));
+    let n0001 = dot(g0001, vec4!(pf0.swizzle([0, 1, 2]), pf1.w()));
+    let n1001 = dot(g1001, vec4!(pf1.x(), pf0.swizzle([1, 2]), pf1.w()));
+    let n0101 = dot(g0101, vec4!(pf0.x(), pf1.y(), pf0.z(), pf1.w()));
+    let n1101 = dot(g1101, vec4!(pf1.swizzle([0, 1]), pf0.z(), pf1.w()));
+    let n0011 = dot(g0011, vec4!(pf0.swizzle([0, 1]), pf1.swizzle([2, 3])));
+    let n1011 = dot(g1011, vec4!(pf1.x(), pf0.y(), pf1.swizzle([2, 3])));
+    let n0111 = dot(g0111, vec4!(pf0.x(), pf1.swizzle([1, 2, 3])));
+    let n1111 = dot(g1111, pf1);
+
+    let fade_xyzw = map(pf0, fade);
+    let n_0w = mix(vec4!(n0000, n1000, n0100, n1100), vec4!(n0001, n1001, n0101, n1101), fade_xyzw.w());
+    let n_1w = mix(vec4!(n0010, n1010, n0110, n1110), vec4!(n0011, n1011, n0111, n1111), fade_xyzw.w());
+    let n_zw = mix(n_0w, n_1w, fade_xyzw.z());
+    let n_yzw = mix(n_zw.swizzle([0, 1]), n_zw.swizzle([2, 3]), fade_xyzw.y());
+    let n_xyzw = mix(n_yzw.x(), n_yzw.y(), fade_xyzw.x());
+    2.2 * n_xyzw
+}
+
+fn cellular2(p: Vec2) -> Vec2 {
+    const K: f32 = 0.142857142857; // 1/7
+    const KO: f32 = 0.428571428571; // 3/7
+    const JITTER: f32 = 1.0; // Less gives more regular pattern
+
+    let pi = map(floor(p), mod289);
+    let pf = fract(p);
+    let oi = vec3!(-1.0, 0.0, 1.0);
+    let of = vec3!(-0.5, 0.5, 1.5);
+    let px = map(oi + pi.x(), permute);
+
+    // Squared distances to the feature points of the three cells in one column.
+    let column = |px: f32, offset: f32| {
+        let p = map(oi + (px + pi.y()), permute);
+        let ox = fract(p * K) - KO;
+        let oy = map(floor(p * K), mod7) * K - KO;
+        let dx = ox * JITTER + (pf.x() + offset);
+        let dy = (vec3!(pf.y()) - of) + oy * JITTER;
+
+        dx * dx + dy * dy
+    };
+
+    let d1 = column(px.x(), 0.5);
+    let d2 = column(px.y(), -0.5);
+    let d3 = column(px.z(), -1.5);
+
+    closest_two([d1, d2, d3].into_iter().flat_map(Vec3::as_array))
+}
+
+fn cellular3(p: Vec3) -> Vec2 {
+    const K: f32 = 0.142857142857; // 1/7
+    const KO: f32 = 0.428571428571; // 1/2-K/2
+    const K2: f32 = 0.020408163265306; // 1/(7*7)
+    const KZ: f32 = 0.166666666667; // 1/6
+    const KZO: f32 = 0.416666666667; // 1/2-1/6*2
+    const JITTER: f32 = 1.0; // smaller jitter gives more regular pattern
+
+    let pi = map(floor(p), mod289);
+    let pf = fract(p) - 0.5;
+
+    let pfx = vec3!(1.0, 0.0, -1.0) + pf.x();
+    let pfy = (vec3!(1.0, 0.0, -1.0) + pf.y()).as_array();
+    let pfz = (vec3!(1.0, 0.0, -1.0) + pf.z()).as_array();
+
+    let p = map(vec3!(-1.0, 0.0, 1.0) + pi.x(), permute);
+
+    let mut distances = [0.0; 27];
+    for (j, offset_y) in [-1.0, 0.0, 1.0].into_iter().enumerate() {
+        let pj = map(p + pi.y() + offset_y, permute);
+
+        for (k, offset_z) in [-1.0, 0.0, 1.0].into_iter().enumerate() {
+            let pjk = map(pj + pi.z() + offset_z, permute);
+
+            let ox = fract(pjk * K) - KO;
+            let oy = map(floor(pjk * K), mod7) * K - KO;
+            let oz = floor(pjk * K2) * KZ - KZO; // pjk < 289 guaranteed
+
+            let dx = pfx + ox * JITTER;
+            let dy = oy * JITTER + pfy[j];
+            let dz = oz * JITTER + pfz[k];
+
+            let d = dx * dx + dy * dy + dz * dz;
+            distances[(j * 3 + k) * 3..][..3].copy_from_slice(d.as_slice());
+        }
+    }
+
+    closest_two(distances)
+}
+
+fn psrdnoise2(x: Vec2, period: Vec2, alpha: f32, gradient: &mut Vec2) -> f32 {
+    // Transform to simplex space (axis-aligned hexagonal grid)
+    let uv = vec2!(x.x() + x.y() * 0.5, x.y());
+
+    // Determine which simplex we're in, with i0 being the "base"
+    let i0 = floor(uv);
+    let f0 = fract(uv);
+    let cmp = step(f0.y(), f0.x());
+    let o1 = vec2!(cmp, 1.0 - cmp);
+    let i1 = i0 + o1;
+    let i2 = i0 + vec2!(1.0, 1.0);
+
+    // Transform corners back to texture space
+    let v0 = vec2!(i0.x() - i0.y() * 0.5, i0.y());
+    let v1 = vec2!(v0.x() + o1.x() - o1.y() * 0.5, v0.y() + o1.y());
+    let v2 = vec2!(v0.x() + 0.5, v0.y() + 1.0);
+
+    let x0 = x - v0;
+    let x1 = x - v1;
+    let x2 = x - v2;
+
+    // Wrap to the periods, if desired, and compute the hash
+    let (iu, iv) = if period.x() > 0.0 || period.y() > 0.0 {
+        let mut xw = vec3!(v0.x(), v1.x(), v2.x());
+        let mut yw = vec3!(v0.y(), v1.y(), v2.y());
+        if period.x() > 0.0 {
+            xw = map(xw, |a| glsl_mod(a, period.x()));
+        }
+        if period.y() > 0.0 {
+            yw = map(yw, |a| glsl_mod(a, period.y()));
+        }
+
+        (floor(xw + yw * 0.5 + 0.5), floor(yw + 0.5))
+    } else {
+        (vec3!(i0.x(), i1.x(), i2.x()), vec3!(i0.y(), i1.y(), i2.y()))
+    };
+
+    let hash = map(iu, |a| glsl_mod(a, 289.0));
+    let hash = zip(hash, iv, |h, v| glsl_mod((h * 51.0 + 2.0) * h + v, 289.0));
+    let hash = map(hash, |h| glsl_mod((h * 34.0 + 10.0) * h, 289.0));
+
+    // Pick a gradient on the unit circle, rotated by alpha
+    let psi = hash * 0.07482 + alpha;
+    let gx = map(psi, f32::cos);
+    let gy = map(psi, f32::sin);
+    let g0 = vec2!(gx.x(), gy.x());
+    let g1 = vec2!(gx.y(), gy.y());
+    let g2 = vec2!(gx.z(), gy.z());
+
+    // Radial decay with distance from each simplex corner
+    let w = max(vec3!(0.8) - vec3!(dot(x0, x0), dot(x1, x1), dot(x2, x2)), 0.0);
+    let w2 = w * w;
+    let w4 = w2 * w2;
+
+    let gdotx = vec3!(dot(g0, x0), dot(g1, x1), dot(g2, x2));
+    let n = dot(w4, gdotx);
+
+    let w3 = w2 * w;
+    let dw = w3 * -8.0 * gdotx;
+    let dn0 = g0 * w4.x() + x0 * dw.x();
+    let dn1 = g1 * w4.y() + x1 * dw.y();
+    let dn2 = g2 * w4.z() + x2 * dw.z();
+    *gradient = (dn0 + dn1 + dn2) * 10.9;
+
+    10.9 * n
+}
+
+fn psrd_permute(i: Vec4) -> Vec4 {
+    map(i, |a| {
+        let im = glsl_mod(a, 289.0);
+        glsl_mod(((im * 34.0) + 10.0) * im, 289.0)
+    })
+}
+
+fn psrdnoise3(x: Vec3, period: Vec3, alpha: f32, gradient: &mut Vec3) -> f32 {
+    // M = mat3(0, 1, 1, 1, 0, 1, 1, 1, 0) and Mi = inverse(M) are symmetric, so the products are written out.
+    let m = |v: Vec3| vec3!(v.y() + v.z(), v.x() + v.z(), v.x() + v.y());
+    let mi = |v: Vec3| vec3!(
+        -0.5 * v.x() + 0.5 * v.y() + 0.5 * v.z(),
+        0.5 * v.x() - 0.5 * v.y() + 0.5 * v.z(),
+        0.5 * v.x() + 0.5 * v.y() - 0.5 * v.z()
+    );
+
+    // Transform to simplex space (tetrahedral grid)
+    let uvw = m(x);
+
+    // Determine which simplex we're in, i0 is the "base corner"
+    let i0 = floor(uvw);
+    let f0 = fract(uvw);
+
+    let g_ = zip(f0.swizzle([0, 1, 0]), f0.swizzle([1, 2, 2]), step);
+    let l_ = vec3!(1.0) - g_;
+    let g = vec3!(l_.z(), g_.swizzle([0, 1]));
+    let l = vec3!(l_.swizzle([0, 1]), g_.z());
+    let o1 = zip(g, l, f32::min);
+    let o2 = zip(g, l, f32::max);
+
+    let i1 = i0 + o1;
+    let i2 = i0 + o2;
+    let i3 = i0 + vec3!(1.0);
+
+    // Transform the corners back to texture space
+    let v0 = mi(i0);
+    let v1 = mi(i1);
+    let v2 = mi(i2);
+    let v3 = mi(i3);
+
+    let x0 = x - v0;
+    let x1 = x - v1;
+    let x2 = x - v2;
+    let x3 = x - v3;
+
+    // Wrap to the periods, if desired
+    let (i0, i1, i2, i3) = if period.x() > 0.0 || period.y() > 0.0 || period.z() > 0.0 {
+        let mut vx = vec4!(v0.x(), v1.x(), v2.x(), v3.x());
+        let mut vy = vec4!(v0.y(), v1.y(), v2.y(), v3.y());
+        let mut vz = vec4!(v0.z(), v1.z(), v2.z(), v3.z());
+        if period.x() > 0.0 {
+            vx = map(vx, |a| glsl_mod(a, period.x()));
+        }
+        if period.y() > 0.0 {
+            vy = map(vy, |a| glsl_mod(a, period.y()));
+        }
+        if period.z() > 0.0 {
+            vz = map(vz, |a| glsl_mod(a, period.z()));
+        }
+
+        (
+            floor(m(vec3!(vx.x(), vy.x(), vz.x())) + 0.5),
+            floor(m(vec3!(vx.y(), vy.y(), vz.y())) + 0.5),
+            floor(m(vec3!(vx.z(), vy.z(), vz.z())) + 0.5),
+            floor(m(vec3!(vx.w(), vy.w(), vz.w())) + 0.5)
+        )
+    } else {
+        (i0, i1, i2, i3)
+    };
+
+    // Compute one pseudo-random hash value for each corner
+    let hash = psrd_permute(vec4!(i0.z(), i1.z(), i2.z(), i3.z()));
+    let hash = psrd_permute(hash + vec4!(i0.y(), i1.y(), i2.y(), i3.y()));
+    let hash = psrd_permute(hash + vec4!(i0.x(), i1.x(), i2.x(), i3.x()));
+
+    // Compute generating gradients from a Fibonacci spiral on the unit sphere
+    let theta = hash * 3.883222077; // 2*pi/golden ratio
+    let sz = hash * -0.006920415 + 0.996539792; // 1-(hash+0.5)*2/289
+    let psi = hash * 0.108705628; // 10*pi/289, chosen to avoid correlation
+
+    let ct = map(theta, f32::cos);
+    let st = map(theta, f32::sin);
+    let sz_prime = map(sz, |a| (1.0 - a * a).sqrt());
+
+    let (gx, gy, gz) = if alpha != 0.0 {
+        // Rotate the gradients by alpha around a pseudo-random orthogonal axis
+        let px = ct * sz_prime;
+        let py = st * sz_prime;
+        let pz = sz;
+        let sp = map(psi, f32::sin);
+        let cp = map(psi, f32::cos);
+        let ctp = st * sp - ct * cp;
+        let qx = (ctp * st) * (vec4!(1.0) - sz) + sp * sz; // mix(Ctp*St, Sp, sz)
+        let qy = (ctp * ct * -1.0) * (vec4!(1.0) - sz) + cp * sz; // mix(-Ctp*Ct, Cp, sz)
+        let qz = (py * cp + px * sp) * -1.0;
+        let sa = alpha.sin();
+        let ca = alpha.cos();
+
+        (px * ca + qx * sa, py * ca + qy * sa, pz * ca + qz * sa)
+    } else {
+        (ct * sz_prime, st * sz_prime, sz)
+    };
+
+    let g0 = vec3!(gx.x(), gy.x(), gz.x());
+    let g1 = vec3!(gx.y(), gy.y(), gz.y());
+    let g2 = vec3!(gx.z(), gy.z(), gz.z());
+    let g3 = vec3!(gx.w(), gy.w(), gz.w());
+
+    // Radial decay with distance from each simplex corner
+    let w = max(vec4!(0.5) - vec4!(dot(x0, x0), dot(x1, x1), dot(x2, x2), dot(x3, x3)), 0.0);
+    let w2 = w * w;
+    let w3 = w2 * w;
+
+    let gdotx = vec4!(dot(g0, x0), dot(g1, x1), dot(g2, x2), dot(g3, x3));
+    let n = dot(w3, gdotx);
+
+    let dw = w2 * -6.0 * gdotx;
+    let dn0 = g0 * w3.x() + x0 * dw.x();
+    let dn1 = g1 * w3.y() + x1 * dw.y();
+    let dn2 = g2 * w3.z() + x2 * dw.z();
+    let dn3 = g3 * w3.w() + x3 * dw.w();
+    *gradient = (dn0 + dn1 + dn2 + dn3) * 39.5;
+
+    39.5 * n
+}
\ No newline at end of file
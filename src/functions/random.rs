@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+
+//! Random value generation in the style of glm's `gtc/random`, plus [`Standard`] distributions so that
+//! `rng.gen::<Vec3>()` and `rng.gen::<Mat4>()` fill every component uniformly from [0, 1).
+
+use std::{f32::consts::TAU, ops::{Add, Mul, Sub}};
+
+use rand::{distributions::{Distribution, Standard}, Rng};
+
+use crate::{
+    matrices::{Mat2, Mat3, Mat4, MatN},
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4, VecN},
+    check_finite, ElementWise,
+};
+
+use super::geometric::length;
+
+/// Samples each component uniformly from [min, max).
+pub fn linearRand<const R: usize, const C: usize, T, G>(min: T, max: T, rng: &mut G) -> T
+    where
+        T: ElementWise<R, C> + Add<Output = T> + Mul<Output = T> + Sub<Output = T>,
+        G: Rng + ?Sized {
+    let t = min.operate(|el| *el = rng.gen());
+
+    let result = min + (max - min) * t;
+    check_finite!("linearRand", crate::nan_checks::elements(result), crate::nan_checks::elements(min), crate::nan_checks::elements(max));
+
+    result
+}
+
+/// Samples each component from a normal distribution with the given mean and standard deviation.
+pub fn gaussRand<const R: usize, const C: usize, T, G>(mean: T, deviation: T, rng: &mut G) -> T
+    where
+        T: ElementWise<R, C> + Add<Output = T> + Mul<Output = T>,
+        G: Rng + ?Sized {
+    let z = mean.operate(|el| *el = standard_normal(rng));
+
+    let result = mean + deviation * z;
+    check_finite!("gaussRand", crate::nan_checks::elements(result), crate::nan_checks::elements(mean), crate::nan_checks::elements(deviation));
+
+    result
+}
+
+/// A uniformly distributed point on a circle of the given radius.
+pub fn circularRand<G: Rng + ?Sized>(radius: f32, rng: &mut G) -> Vec2 {
+    let angle = rng.gen_range(0.0..TAU);
+
+    Vec2::_new(angle.cos(), angle.sin()) * radius
+}
+
+/// A uniformly distributed point on a sphere of the given radius.
+pub fn sphericalRand<G: Rng + ?Sized>(radius: f32, rng: &mut G) -> Vec3 {
+    let z: f32 = rng.gen_range(-1.0..=1.0);
+    let angle = rng.gen_range(0.0..TAU);
+    let r = (1.0 - z * z).sqrt();
+
+    Vec3::_new(r * angle.cos(), r * angle.sin(), z) * radius
+}
+
+/// A uniformly distributed point inside a disk of the given radius.
+pub fn diskRand<G: Rng + ?Sized>(radius: f32, rng: &mut G) -> Vec2 {
+    loop {
+        let p = Vec2::_new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+
+        if length(p) <= 1.0 {
+            return p * radius;
+        }
+    }
+}
+
+/// A uniformly distributed point inside a ball of the given radius.
+pub fn ballRand<G: Rng + ?Sized>(radius: f32, rng: &mut G) -> Vec3 {
+    loop {
+        let p = Vec3::_new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+
+        if length(p) <= 1.0 {
+            return p * radius;
+        }
+    }
+}
+
+/// Marsaglia's polar method.
+fn standard_normal<G: Rng + ?Sized>(rng: &mut G) -> f32 {
+    loop {
+        let x1: f32 = rng.gen_range(-1.0..1.0);
+        let x2: f32 = rng.gen_range(-1.0..1.0);
+        let w = x1 * x1 + x2 * x2;
+
+        if w < 1.0 && w > 0.0 {
+            return x2 * ((-2.0 * w.ln()) / w).sqrt();
+        }
+    }
+}
+
+macro_rules! standard_distribution {
+    ($t:tt) => {
+        impl Distribution<$t> for Standard {
+            fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> $t {
+                $t::from_array(rng.gen())
+            }
+        }
+    };
+}
+
+standard_distribution!(Vec2);
+standard_distribution!(Vec3);
+standard_distribution!(Vec4);
+standard_distribution!(IVec2);
+standard_distribution!(IVec3);
+standard_distribution!(IVec4);
+standard_distribution!(UVec2);
+standard_distribution!(UVec3);
+standard_distribution!(UVec4);
+standard_distribution!(Mat2);
+standard_distribution!(Mat3);
+standard_distribution!(Mat4);
\ No newline at end of file
@@ -0,0 +1,121 @@
+//! Low-discrepancy sequences and sample warping, for temporal anti-aliasing and path tracing.
+
+use std::f32::consts::TAU;
+
+use crate::{check_finite, matrices::Mat4, vectors::{Vec2, Vec3}};
+
+/// The radical inverse of `index` in the given base: element `index` of the Halton sequence for that base.
+///
+/// Index 0 maps to 0, so TAA jitter sequences usually start at index 1.
+///
+/// # Panics
+///
+/// Panics if `base` is less than 2.
+pub fn halton(index: u32, base: u32) -> f32 {
+    assert!(base >= 2, "halton base must be at least 2");
+
+    let inv_base = 1.0 / base as f64;
+    let mut fraction = inv_base;
+    let mut result = 0.0;
+    let mut i = index;
+
+    while i > 0 {
+        result += (i % base) as f64 * fraction;
+        i /= base;
+        fraction *= inv_base;
+    }
+
+    below_one(result)
+}
+
+/// Element `index` of the Halton (2, 3) sequence, in [0, 1)².
+pub fn halton23(index: u32) -> Vec2 {
+    Vec2::_new(halton(index, 2), halton(index, 3))
+}
+
+/// Element `index` of Martin Roberts' R2 sequence, in [0, 1)².
+pub fn r2(index: u32) -> Vec2 {
+    // The plastic number, the unique real root of x³ = x + 1
+    const G: f64 = 1.324_717_957_244_746;
+    const A1: f64 = 1.0 / G;
+    const A2: f64 = 1.0 / (G * G);
+
+    let x = (0.5 + A1 * index as f64).fract();
+    let y = (0.5 + A2 * index as f64).fract();
+
+    Vec2::_new(below_one(x), below_one(y))
+}
+
+/// Element `index` of the two-dimensional Sobol sequence, in [0, 1)².
+pub fn sobol2(index: u32) -> Vec2 {
+    // The first dimension is the base 2 van der Corput sequence. The second uses the direction numbers
+    // of the primitive polynomial x + 1, where each one is the previous one xor-ed with itself shifted right.
+    let x = index.reverse_bits();
+
+    let mut y = 0u32;
+    let mut direction = 1u32 << 31;
+    let mut i = index;
+    while i > 0 {
+        if i & 1 == 1 {
+            y ^= direction;
+        }
+
+        direction ^= direction >> 1;
+        i >>= 1;
+    }
+
+    Vec2::_new(to_unit_f32(x), to_unit_f32(y))
+}
+
+/// Converts a 32-bit fixed point fraction to a float, keeping the 24 bits a float can represent exactly.
+fn to_unit_f32(x: u32) -> f32 {
+    (x >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+}
+
+/// Narrows a fraction in [0, 1) to a float. Fractions within half an ulp of 1 would round up to 1.0, so they are
+/// clamped to the largest float below it.
+fn below_one(x: f64) -> f32 {
+    (x as f32).min(1.0 - f32::EPSILON / 2.0)
+}
+
+/// Offsets a projection matrix by a sub-pixel amount.
+///
+/// `jitter` is measured in pixels, typically a low-discrepancy sample shifted to [-0.5, 0.5)², and `resolution`
+/// is the size of the render target in pixels. The offset is applied in clip space, so this works for both
+/// perspective and orthographic projections.
+pub fn jitter_projection(projection: Mat4, jitter: Vec2, resolution: Vec2) -> Mat4 {
+    let offset = (jitter * 2.0) / resolution;
+    let mut m = projection.0;
+
+    // Equivalent to translate(offset.x, offset.y, 0) * projection: x_clip += offset.x * w_clip
+    let w = m.row(3).into_owned();
+    m.set_row(0, &(m.row(0) + w * offset.x()));
+    m.set_row(1, &(m.row(1) + w * offset.y()));
+    check_finite!("jitter_projection", m, projection.0, jitter.0, resolution.0);
+
+    Mat4(m)
+}
+
+/// Maps a point in [0, 1)² to a direction on the unit hemisphere around +z, with uniform density over solid angle.
+pub fn uniform_hemisphere(u: Vec2) -> Vec3 {
+    let z = u.x();
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let phi = TAU * u.y();
+
+    let result = Vec3::_new(r * phi.cos(), r * phi.sin(), z);
+    check_finite!("uniform_hemisphere", result.0, u.0);
+
+    result
+}
+
+/// Maps a point in [0, 1)² to a direction on the unit hemisphere around +z, with density proportional to the cosine
+/// of the angle with +z.
+pub fn cosine_hemisphere(u: Vec2) -> Vec3 {
+    let r = u.x().sqrt();
+    let phi = TAU * u.y();
+
+    let result = Vec3::_new(r * phi.cos(), r * phi.sin(), (1.0 - u.x()).max(0.0).sqrt());
+    check_finite!("cosine_hemisphere", result.0, u.0);
+
+    result
+}
\ No newline at end of file
@@ -1,28 +1,23 @@
 #![allow(non_snake_case)]
 
-use crate::{matrices::Mat4, vectors::Vec3};
+use crate::{check_finite, matrices::Mat4, vectors::{Vec3, Vec4}};
 
 use super::geometric::{cross, dot, normalize};
 
 pub fn lookAt(eye: Vec3, center: Vec3, up: Vec3) -> Mat4 {
     let back = normalize(eye - center);
-    let right = normalize(cross(back, up));
-    let up = cross(right, back);
-
-    let [rx, ry, rz] = right.0.data.0[0];
-    let [fx, fy, fz] = back.0.data.0[0];
-    let [ux, uy, uz] = up.0.data.0[0];
-
-    let tx = dot(eye, right);
-    let ty = dot(eye, up);
-    let tz = dot(eye, back * -1);
-
-    Mat4::_new(
-        rx, ry, rz, -tx,
-        ux, uy, uz, -ty,
-        fx, fy, fz, -tz,
-        0.0, 0.0, 0.0, 1.0
-    )
+    let right = normalize(cross(up, back));
+    let true_up = cross(back, right);
+
+    let result = Mat4::_from_rows(
+        Vec4::_new(right.x(), right.y(), right.z(), -dot(right, eye)),
+        Vec4::_new(true_up.x(), true_up.y(), true_up.z(), -dot(true_up, eye)),
+        Vec4::_new(back.x(), back.y(), back.z(), -dot(back, eye)),
+        Vec4::_new(0.0, 0.0, 0.0, 1.0)
+    );
+    check_finite!("lookAt", result.0, eye.0, center.0, up.0);
+
+    result
 }
 
 // pub fn rotate(m: &Mat4, angle: f32, axis: &Vec3) -> Mat4 {
@@ -0,0 +1,306 @@
+//! GLSL literal syntax: `Display` prints values as constructor calls like `vec3(1.0, 2.0, 3.0)`, and `FromStr` parses
+//! them back, including mixed forms like `vec4(vec2(1.0, 2.0), 3.0, 4.0)` or `mat3(mat4(...))`.
+//!
+//! Matrices are printed in column order, as GLSL constructors take them. A precision given to the formatter, as in
+//! `format!("{:.3}", v)`, applies to every float component; otherwise floats print with as many digits as it takes to
+//! read them back exactly. Non-finite components print as Rust does (`NaN`, `inf`) and are not valid GLSL.
+
+use std::{fmt::{Display, Formatter}, str::FromStr};
+
+use crate::{
+    matrices::{Mat2, Mat3, Mat4},
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4},
+    GlTypesError,
+};
+
+/// A component type that can be printed as a GLSL literal and read back from one.
+trait Literal: Copy {
+    fn write(self, f: &mut Formatter<'_>) -> std::fmt::Result;
+    fn parse(literal: &str) -> Self;
+}
+
+impl Literal for f32 {
+    fn write(self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}", precision, self),
+            None => write!(f, "{:?}", self),
+        }
+    }
+
+    fn parse(literal: &str) -> Self {
+        // Literals are validated while parsing
+        literal.parse().unwrap()
+    }
+}
+
+impl Literal for i32 {
+    fn write(self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+
+    fn parse(literal: &str) -> Self {
+        // Integer literals wrap, as `as` does; fractional ones truncate toward zero
+        if is_integer(literal) { literal.parse::<i64>().unwrap() as i32 } else { literal.parse::<f64>().unwrap() as i32 }
+    }
+}
+
+impl Literal for u32 {
+    fn write(self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}u", self)
+    }
+
+    fn parse(literal: &str) -> Self {
+        // Integer literals wrap, as `as` does; fractional ones truncate toward zero
+        if is_integer(literal) { literal.parse::<i64>().unwrap() as u32 } else { literal.parse::<f64>().unwrap() as u32 }
+    }
+}
+
+/// Whether `literal` has no fractional part or exponent.
+fn is_integer(literal: &str) -> bool {
+    !literal.contains(['.', 'e', 'E'])
+}
+
+/// A parsed constructor argument. Components are kept as text so that they can be read as the target's scalar type.
+struct Arg<'a> {
+    kind: ArgKind,
+    components: Vec<&'a str>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ArgKind {
+    Scalar,
+    Vector,
+    /// A square matrix of the given size.
+    Matrix(usize),
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> GlTypesError {
+        GlTypesError::InvalidSyntax { position: self.position }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.source[self.position..].chars().next()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), GlTypesError> {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    /// Consumes characters while `f` holds and returns them.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.source[self.position..];
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.position += len;
+
+        &rest[..len]
+    }
+
+    fn ident(&mut self) -> Result<&'a str, GlTypesError> {
+        self.skip_whitespace();
+        let ident = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if ident.is_empty() { Err(self.error()) } else { Ok(ident) }
+    }
+
+    /// A number with an optional sign and GLSL suffix (`u`, `f` or `lf`). Returns it without the suffix.
+    fn number(&mut self) -> Result<&'a str, GlTypesError> {
+        self.skip_whitespace();
+        let start = self.position;
+
+        let sign = self.take_while(|c| c == '-' || c == '+');
+        if sign.len() > 1 {
+            self.position = start;
+            return Err(self.error());
+        }
+
+        self.take_while(|c| c.is_ascii_digit() || c == '.');
+        let rest = &self.source[self.position..];
+        if rest.starts_with(['e', 'E']) {
+            self.position += 1;
+            self.take_while(|c| c == '-' || c == '+');
+            self.take_while(|c| c.is_ascii_digit());
+        }
+
+        let number = &self.source[start..self.position];
+        let number = number.strip_prefix('+').unwrap_or(number);
+        // Integers must fit in an i64, and floats in an f32
+        let valid = if is_integer(number) {
+            number.parse::<i64>().is_ok()
+        } else {
+            number.parse::<f32>().is_ok_and(f32::is_finite)
+        };
+        if !valid || number.contains(['i', 'n', 'I', 'N']) {
+            self.position = start;
+            return Err(self.error());
+        }
+
+        let suffix = self.take_while(|c| c.is_ascii_alphabetic());
+        if !matches!(suffix, "" | "u" | "U" | "f" | "F" | "lf" | "LF") {
+            self.position -= suffix.len();
+            return Err(self.error());
+        }
+
+        Ok(number)
+    }
+
+    fn arg(&mut self) -> Result<Arg<'a>, GlTypesError> {
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.position;
+                let name = self.ident()?;
+                let (kind, len) = match kind_of(name) {
+                    Some(kind) => kind,
+                    None => {
+                        self.position = start;
+                        return Err(self.error());
+                    }
+                };
+
+                let components = self.constructor(kind, len)?;
+                Ok(Arg { kind, components })
+            },
+            _ => Ok(Arg { kind: ArgKind::Scalar, components: vec![self.number()?] }),
+        }
+    }
+
+    /// The arguments of a constructor for a type with `len` components, flattened by GLSL's rules.
+    fn constructor(&mut self, kind: ArgKind, len: usize) -> Result<Vec<&'a str>, GlTypesError> {
+        self.expect('(')?;
+        let mut args = vec![self.arg()?];
+        while self.peek() == Some(',') {
+            self.expect(',')?;
+            args.push(self.arg()?);
+        }
+        self.expect(')')?;
+
+        match (kind, &args[..]) {
+            (ArgKind::Vector, [Arg { kind: ArgKind::Scalar, components }]) => Ok(vec![components[0]; len]),
+            (ArgKind::Matrix(n), [Arg { kind: ArgKind::Scalar, components }]) => {
+                Ok((0..len).map(|i| if i / n == i % n { components[0] } else { "0" }).collect())
+            },
+            (ArgKind::Matrix(n), [Arg { kind: ArgKind::Matrix(m), components }]) => {
+                // Resizing keeps the top left corner and fills the rest from the identity
+                Ok((0..len).map(|i| {
+                    let (c, r) = (i / n, i % n);
+                    if c < *m && r < *m { components[c * m + r] } else if c == r { "1" } else { "0" }
+                }).collect())
+            },
+            (_, [arg]) if arg.components.len() >= len => Ok(arg.components[..len].to_vec()),
+            _ => {
+                // As for the constructor macros, every argument must contribute at least one component
+                let before_last: usize = args[..args.len() - 1].iter().map(|a| a.components.len()).sum();
+                let total = before_last + args[args.len() - 1].components.len();
+
+                if before_last >= len || total < len {
+                    return Err(GlTypesError::WrongLength { expected: len, found: total });
+                }
+
+                Ok(args.iter().flat_map(|a| a.components.iter().copied()).take(len).collect())
+            },
+        }
+    }
+}
+
+fn kind_of(name: &str) -> Option<(ArgKind, usize)> {
+    let (kind, size) = match name {
+        "vec2" | "ivec2" | "uvec2" => (ArgKind::Vector, 2),
+        "vec3" | "ivec3" | "uvec3" => (ArgKind::Vector, 3),
+        "vec4" | "ivec4" | "uvec4" => (ArgKind::Vector, 4),
+        "mat2" => (ArgKind::Matrix(2), 4),
+        "mat3" => (ArgKind::Matrix(3), 9),
+        "mat4" => (ArgKind::Matrix(4), 16),
+        "float" | "int" | "uint" => (ArgKind::Scalar, 1),
+        _ => return None,
+    };
+
+    Some((kind, size))
+}
+
+/// Parses `source` as a call to the `name` constructor.
+fn parse<T: Literal>(source: &str, name: &'static str) -> Result<Vec<T>, GlTypesError> {
+    let mut parser = Parser { source, position: 0 };
+
+    let start = parser.peek().map(|_| parser.position).unwrap_or(0);
+    let ident = parser.ident()?;
+    if ident != name {
+        return Err(match kind_of(ident) {
+            Some(_) => GlTypesError::WrongType { expected: name },
+            None => GlTypesError::InvalidSyntax { position: start },
+        });
+    }
+
+    let (kind, len) = kind_of(name).unwrap();
+    let components = parser.constructor(kind, len)?;
+
+    if parser.peek().is_some() {
+        return Err(parser.error());
+    }
+
+    Ok(components.into_iter().map(T::parse).collect())
+}
+
+fn write<T: Literal>(f: &mut Formatter<'_>, name: &str, components: &[T]) -> std::fmt::Result {
+    write!(f, "{}(", name)?;
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        c.write(f)?;
+    }
+
+    write!(f, ")")
+}
+
+macro_rules! glsl_literal {
+    ($($t:ident, $s:ty, $name:literal);+ $(;)?) => {
+        $(
+            impl Display for $t {
+                fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                    write(f, $name, self.0.as_slice())
+                }
+            }
+
+            impl FromStr for $t {
+                type Err = GlTypesError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let components = parse::<$s>(s, $name)?;
+
+                    <$t>::try_from(&components[..])
+                }
+            }
+        )+
+    };
+}
+
+glsl_literal!(
+    Vec2, f32, "vec2";
+    Vec3, f32, "vec3";
+    Vec4, f32, "vec4";
+    IVec2, i32, "ivec2";
+    IVec3, i32, "ivec3";
+    IVec4, i32, "ivec4";
+    UVec2, u32, "uvec2";
+    UVec3, u32, "uvec3";
+    UVec4, u32, "uvec4";
+    Mat2, f32, "mat2";
+    Mat3, f32, "mat3";
+    Mat4, f32, "mat4";
+);
@@ -0,0 +1,68 @@
+use cgmath::{Matrix2, Matrix3, Matrix4, Point2, Point3, Vector2, Vector3, Vector4};
+
+use crate::{
+    check_finite,
+    matrices::{Mat2, Mat3, Mat4, MatN},
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4},
+};
+
+macro_rules! cgmath_vector {
+    ($($t:ident, $c:ident, $s:ty, $n:literal);+ $(;)?) => {
+        $(
+            impl From<$c<$s>> for $t {
+                fn from(value: $c<$s>) -> Self {
+                    let array: [$s; $n] = value.into();
+                    let result = Self(array.into());
+                    check_finite!(concat!(stringify!($t), "::from"), result.0);
+
+                    result
+                }
+            }
+
+            impl From<$t> for $c<$s> {
+                fn from(value: $t) -> Self {
+                    value.0.data.0[0].into()
+                }
+            }
+        )+
+    };
+}
+
+cgmath_vector!(
+    Vec2, Vector2, f32, 2;
+    Vec3, Vector3, f32, 3;
+    Vec4, Vector4, f32, 4;
+    IVec2, Vector2, i32, 2;
+    IVec3, Vector3, i32, 3;
+    IVec4, Vector4, i32, 4;
+    UVec2, Vector2, u32, 2;
+    UVec3, Vector3, u32, 3;
+    UVec4, Vector4, u32, 4;
+    Vec2, Point2, f32, 2;
+    Vec3, Point3, f32, 3;
+);
+
+macro_rules! cgmath_matrix {
+    ($($t:ident, $c:ident);+ $(;)?) => {
+        $(
+            // cgmath converts its matrices to and from `[[S; N]; N]` as arrays of columns
+            impl From<$c<f32>> for $t {
+                fn from(value: $c<f32>) -> Self {
+                    Self::from_array(value.into())
+                }
+            }
+
+            impl From<$t> for $c<f32> {
+                fn from(value: $t) -> Self {
+                    value.as_array().into()
+                }
+            }
+        )+
+    };
+}
+
+cgmath_matrix!(
+    Mat2, Matrix2;
+    Mat3, Matrix3;
+    Mat4, Matrix4;
+);
@@ -0,0 +1,64 @@
+use crate::{
+    check_finite,
+    matrices::{Mat2, Mat3, Mat4, MatN},
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4},
+};
+
+macro_rules! glam_vector {
+    ($($t:ident);+ $(;)?) => {
+        $(
+            impl From<glam::$t> for $t {
+                fn from(value: glam::$t) -> Self {
+                    let result = Self(value.to_array().into());
+                    check_finite!(concat!(stringify!($t), "::from"), result.0);
+
+                    result
+                }
+            }
+
+            impl From<$t> for glam::$t {
+                fn from(value: $t) -> Self {
+                    Self::from_array(value.0.data.0[0])
+                }
+            }
+        )+
+    };
+}
+
+glam_vector!(Vec2; Vec3; Vec4; IVec2; IVec3; IVec4; UVec2; UVec3; UVec4);
+
+// glam's SIMD-aligned Vec3A is still three components, so it maps to Vec3 like glam::Vec3 does
+impl From<glam::Vec3A> for Vec3 {
+    fn from(value: glam::Vec3A) -> Self {
+        let result = Self(value.to_array().into());
+        check_finite!("Vec3::from", result.0);
+
+        result
+    }
+}
+
+impl From<Vec3> for glam::Vec3A {
+    fn from(value: Vec3) -> Self {
+        Self::from_array(value.0.data.0[0])
+    }
+}
+
+macro_rules! glam_matrix {
+    ($($t:ident);+ $(;)?) => {
+        $(
+            impl From<glam::$t> for $t {
+                fn from(value: glam::$t) -> Self {
+                    Self::from_array(value.to_cols_array_2d())
+                }
+            }
+
+            impl From<$t> for glam::$t {
+                fn from(value: $t) -> Self {
+                    Self::from_cols_array_2d(&value.as_array())
+                }
+            }
+        )+
+    };
+}
+
+glam_matrix!(Mat2; Mat3; Mat4);
@@ -0,0 +1,78 @@
+use mint::{ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, RowMatrix2, RowMatrix3, RowMatrix4, Vector2, Vector3, Vector4};
+
+use crate::{
+    check_finite,
+    matrices::{Mat2, Mat3, Mat4, MatN},
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4},
+};
+
+macro_rules! mint_vector {
+    ($($t:ident, $m:ident, $s:ty, $n:literal);+ $(;)?) => {
+        $(
+            impl From<$m<$s>> for $t {
+                fn from(value: $m<$s>) -> Self {
+                    let array: [$s; $n] = value.into();
+                    let result = Self(array.into());
+                    check_finite!(concat!(stringify!($t), "::from"), result.0);
+
+                    result
+                }
+            }
+
+            impl From<$t> for $m<$s> {
+                fn from(value: $t) -> Self {
+                    value.0.data.0[0].into()
+                }
+            }
+        )+
+    };
+}
+
+mint_vector!(
+    Vec2, Vector2, f32, 2;
+    Vec3, Vector3, f32, 3;
+    Vec4, Vector4, f32, 4;
+    IVec2, Vector2, i32, 2;
+    IVec3, Vector3, i32, 3;
+    IVec4, Vector4, i32, 4;
+    UVec2, Vector2, u32, 2;
+    UVec3, Vector3, u32, 3;
+    UVec4, Vector4, u32, 4;
+);
+
+macro_rules! mint_matrix {
+    ($($t:ident, $column:ident, $row:ident);+ $(;)?) => {
+        $(
+            // mint's arrays of arrays are columns for column matrices and rows for row matrices
+            impl From<$column<f32>> for $t {
+                fn from(value: $column<f32>) -> Self {
+                    Self::from_array(value.into())
+                }
+            }
+
+            impl From<$t> for $column<f32> {
+                fn from(value: $t) -> Self {
+                    value.as_array().into()
+                }
+            }
+
+            impl From<$row<f32>> for $t {
+                fn from(value: $row<f32>) -> Self {
+                    Self(Self::from_array(value.into()).0.transpose())
+                }
+            }
+
+            impl From<$t> for $row<f32> {
+                fn from(value: $t) -> Self {
+                    Self::from(value.0.transpose().data.0)
+                }
+            }
+        )+
+    };
+}
+
+mint_matrix!(
+    Mat2, ColumnMatrix2, RowMatrix2;
+    Mat3, ColumnMatrix3, RowMatrix3;
+    Mat4, ColumnMatrix4, RowMatrix4;
+);
@@ -0,0 +1,9 @@
+//! Conversions to and from the math types of other crates.
+
+mod nalgebra;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "cgmath")]
+mod cgmath;
@@ -0,0 +1,53 @@
+use nalgebra::{Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4};
+
+use crate::{
+    check_finite,
+    matrices::{Mat2, Mat3, Mat4},
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4},
+};
+
+macro_rules! nalgebra_conversion {
+    ($($t:ident, $n:ty);+ $(;)?) => {
+        $(
+            impl $t {
+                /// The underlying nalgebra value, so that nalgebra's algorithms apply without copying.
+                pub fn as_nalgebra(&self) -> &$n {
+                    &self.0
+                }
+
+                pub fn as_nalgebra_mut(&mut self) -> &mut $n {
+                    &mut self.0
+                }
+            }
+
+            impl From<$n> for $t {
+                fn from(value: $n) -> Self {
+                    check_finite!(concat!(stringify!($t), "::from"), value);
+
+                    Self(value)
+                }
+            }
+
+            impl From<$t> for $n {
+                fn from(value: $t) -> Self {
+                    value.0
+                }
+            }
+        )+
+    };
+}
+
+nalgebra_conversion!(
+    Vec2, Vector2<f32>;
+    Vec3, Vector3<f32>;
+    Vec4, Vector4<f32>;
+    IVec2, Vector2<i32>;
+    IVec3, Vector3<i32>;
+    IVec4, Vector4<i32>;
+    UVec2, Vector2<u32>;
+    UVec3, Vector3<u32>;
+    UVec4, Vector4<u32>;
+    Mat2, Matrix2<f32>;
+    Mat3, Matrix3<f32>;
+    Mat4, Matrix4<f32>;
+);
@@ -0,0 +1,92 @@
+//! Zero-copy views of slices of vectors and matrices as their components or bytes, for buffer uploads.
+//!
+//! Every type is `#[repr(C)]` around nalgebra's `#[repr(C)]` matrix of `#[repr(transparent)]` array storage, so a
+//! value is exactly its components in column-major order, with the alignment of its scalar and no padding.
+
+use std::mem::{align_of, size_of};
+
+use crate::{
+    matrices::{Mat2, Mat3, Mat4},
+    private::Seal,
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4},
+    GlTypesError,
+};
+
+/// A type that is laid out as `COMPONENTS` consecutive values of `Scalar`.
+pub trait Flat: Seal + Copy {
+    type Scalar: Copy;
+    const COMPONENTS: usize;
+}
+
+macro_rules! flat {
+    ($($t:ident, $s:ty, $n:literal);+ $(;)?) => {
+        $(
+            impl Flat for $t {
+                type Scalar = $s;
+                const COMPONENTS: usize = $n;
+            }
+
+            const _: () = assert!(size_of::<$t>() == $n * size_of::<$s>() && align_of::<$t>() == align_of::<$s>());
+
+            #[cfg(feature = "bytemuck")]
+            // SAFETY: all zeroes is a valid value of every scalar, and so of the components the layout check allows
+            unsafe impl bytemuck::Zeroable for $t {}
+
+            #[cfg(feature = "bytemuck")]
+            // SAFETY: the type is `#[repr(C)]`, `Copy` and `'static`, the assert above rules out padding, and every bit
+            // pattern is a valid `f32`, `i32` or `u32`, so any bytes of the right length are a valid value
+            unsafe impl bytemuck::Pod for $t {}
+        )+
+    };
+}
+
+flat!(
+    Vec2, f32, 2;
+    Vec3, f32, 3;
+    Vec4, f32, 4;
+    IVec2, i32, 2;
+    IVec3, i32, 3;
+    IVec4, i32, 4;
+    UVec2, u32, 2;
+    UVec3, u32, 3;
+    UVec4, u32, 4;
+    Mat2, f32, 4;
+    Mat3, f32, 9;
+    Mat4, f32, 16;
+);
+
+/// The components of `values`, one value after another. Matrices are in column-major order.
+pub fn as_flat_slice<T: Flat>(values: &[T]) -> &[T::Scalar] {
+    // SAFETY: `T` is exactly `T::COMPONENTS` scalars with the scalar's alignment
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), values.len() * T::COMPONENTS) }
+}
+
+pub fn as_flat_slice_mut<T: Flat>(values: &mut [T]) -> &mut [T::Scalar] {
+    // SAFETY: as for `as_flat_slice`, and every bit pattern is a valid `T`
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len() * T::COMPONENTS) }
+}
+
+/// The bytes of `values` in native endianness, ready to copy into a GPU buffer.
+pub fn as_bytes<T: Flat>(values: &[T]) -> &[u8] {
+    // SAFETY: there is no padding, so every byte is initialized
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), std::mem::size_of_val(values)) }
+}
+
+/// Views consecutive components as values of `T`. Fails unless the length is a multiple of `T::COMPONENTS`.
+pub fn from_flat_slice<T: Flat>(flat: &[T::Scalar]) -> Result<&[T], GlTypesError> {
+    if flat.len() % T::COMPONENTS != 0 {
+        return Err(GlTypesError::NotAMultiple { components: T::COMPONENTS, found: flat.len() });
+    }
+
+    // SAFETY: every run of `T::COMPONENTS` scalars is a valid `T`, and the alignments are the same
+    Ok(unsafe { std::slice::from_raw_parts(flat.as_ptr().cast(), flat.len() / T::COMPONENTS) })
+}
+
+pub fn from_flat_slice_mut<T: Flat>(flat: &mut [T::Scalar]) -> Result<&mut [T], GlTypesError> {
+    if flat.len() % T::COMPONENTS != 0 {
+        return Err(GlTypesError::NotAMultiple { components: T::COMPONENTS, found: flat.len() });
+    }
+
+    // SAFETY: as for `from_flat_slice`
+    Ok(unsafe { std::slice::from_raw_parts_mut(flat.as_mut_ptr().cast(), flat.len() / T::COMPONENTS) })
+}
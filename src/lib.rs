@@ -1,11 +1,21 @@
+mod approx_eq;
 mod element_wise;
+mod error;
+mod glsl;
+mod interop;
+mod layout;
+#[cfg(feature = "nan-checks")]
+mod nan_checks;
 
 pub mod vectors;
 pub mod matrices;
 pub mod functions;
 
 pub use functions::*;
+pub use approx_eq::*;
 pub use element_wise::*;
+pub use error::*;
+pub use layout::*;
 
 use num::cast::AsPrimitive;
 
@@ -43,126 +53,592 @@ impl GLScalar for u64 {}
 impl GLScalar for f32 {}
 impl GLScalar for f64 {}
 
+/// With the `nan-checks` feature, panics if `$result` has a NaN or infinite component, reporting `$operation` and the
+/// operands. Expands to nothing otherwise. Results and operands can be `f32`, nalgebra matrices or arrays of `f32`.
+macro_rules! check_finite {
+    ($operation:expr, $result:expr $(, $operand:expr)* $(,)?) => {
+        #[cfg(feature = "nan-checks")]
+        {
+            use $crate::nan_checks::Components;
+
+            if !$result.all_finite() {
+                $crate::nan_checks::report($operation, $result.components(), vec![$($operand.components()),*]);
+            }
+        }
+    };
+}
+
+pub(crate) use check_finite;
+
+/// Like `check_finite!`, for the `const fn` constructors. A const panic can't format the values, so the message only
+/// names `$operation`, and in a const item the check fails at compile time.
+macro_rules! check_finite_const {
+    ($operation:literal, $($component:expr),+ $(,)?) => {
+        #[cfg(feature = "nan-checks")]
+        {
+            assert!($crate::nan_checks::all_finite(&[$($component),+]), concat!($operation, " produced a non-finite result"));
+        }
+    };
+}
+
+pub(crate) use check_finite_const;
+
 macro_rules! matrix_arithmetic {
     ($t:tt) => {
-        impl Add for $t {
+        // All operators are component-wise. `%` is Rust's truncated remainder, not GLSL's floored `mod`.
+
+        matrix_arithmetic!(@common $t);
+        matrix_arithmetic!(@binary $t, Mul, mul, MulAssign, mul_assign, *);
+    };
+    ($t:tt, $v:tt) => {
+        // As in GLSL, `*` between two matrices or a matrix and a vector is the linear algebra product, with the vector
+        // as a column on the right and a row on the left. `matrixCompMult` is the component-wise product. The other
+        // operators, and `*` with a scalar, are component-wise, with `%` as Rust's truncated remainder.
+
+        matrix_arithmetic!(@common $t);
+        matrix_arithmetic!(@scalar $t, Mul, mul, MulAssign, mul_assign, *);
+        matrix_arithmetic!(@product $t, $t, $t, |a, b| a * b);
+        matrix_arithmetic!(@product $t, $v, $v, |a, b| a * b);
+        matrix_arithmetic!(@product $v, $t, $v, |a, b| b.tr_mul(a));
+
+        impl MulAssign for $t {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign<&$t> for $t {
+            fn mul_assign(&mut self, rhs: &$t) {
+                *self = *self * *rhs;
+            }
+        }
+
+        impl MulAssign<$t> for $v {
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign<&$t> for $v {
+            fn mul_assign(&mut self, rhs: &$t) {
+                *self = *self * *rhs;
+            }
+        }
+    };
+    (@common $t:tt) => {
+        matrix_arithmetic!(@binary $t, Add, add, AddAssign, add_assign, +);
+        matrix_arithmetic!(@binary $t, Sub, sub, SubAssign, sub_assign, -);
+        matrix_arithmetic!(@binary $t, Div, div, DivAssign, div_assign, /);
+        matrix_arithmetic!(@binary $t, Rem, rem, RemAssign, rem_assign, %);
+
+        impl Neg for $t {
             type Output = Self;
-        
-            fn add(self, rhs: Self) -> Self::Output {
-                Self(self.0 + rhs.0)
+
+            fn neg(self) -> Self::Output {
+                let result = Self(-self.0);
+                $crate::check_finite!(concat!("-", stringify!($t)), result.0, self.0);
+
+                result
+            }
+        }
+
+        impl Neg for &$t {
+            type Output = $t;
+
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+    };
+    (@product $l:tt, $r:tt, $o:tt, |$a:ident, $b:ident| $product:expr) => {
+        impl Mul<$r> for $l {
+            type Output = $o;
+
+            fn mul(self, rhs: $r) -> Self::Output {
+                let ($a, $b) = (&self.0, &rhs.0);
+                let result = $o($product);
+                $crate::check_finite!(concat!(stringify!($l), " * ", stringify!($r)), result.0, self.0, rhs.0);
+
+                result
+            }
+        }
+
+        impl Mul<&$r> for $l {
+            type Output = $o;
+
+            fn mul(self, rhs: &$r) -> Self::Output {
+                self * *rhs
             }
         }
-        
-        impl Sub for $t {
+
+        impl Mul<$r> for &$l {
+            type Output = $o;
+
+            fn mul(self, rhs: $r) -> Self::Output {
+                *self * rhs
+            }
+        }
+
+        impl Mul<&$r> for &$l {
+            type Output = $o;
+
+            fn mul(self, rhs: &$r) -> Self::Output {
+                *self * *rhs
+            }
+        }
+    };
+    (@binary $t:tt, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl $trait for $t {
             type Output = Self;
-        
-            fn sub(self, rhs: Self) -> Self::Output {
-                Self(self.0 - rhs.0)
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                let result = Self(self.0.zip_map(&rhs.0, |a, b| a $op b));
+                $crate::check_finite!(concat!(stringify!($t), " ", stringify!($op), " ", stringify!($t)), result.0, self.0, rhs.0);
+
+                result
+            }
+        }
+
+        impl $trait<&$t> for $t {
+            type Output = $t;
+
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                self $op *rhs
             }
         }
-        
-        impl Mul for $t {
+
+        impl $trait<$t> for &$t {
+            type Output = $t;
+
+            fn $fn(self, rhs: $t) -> Self::Output {
+                *self $op rhs
+            }
+        }
+
+        impl $trait<&$t> for &$t {
+            type Output = $t;
+
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                *self $op *rhs
+            }
+        }
+
+        impl $assign_trait for $t {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl $assign_trait<&$t> for $t {
+            fn $assign_fn(&mut self, rhs: &$t) {
+                *self = *self $op *rhs;
+            }
+        }
+
+        matrix_arithmetic!(@scalar $t, $trait, $fn, $assign_trait, $assign_fn, $op);
+    };
+    (@scalar $t:tt, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl<T: GLScalar> $trait<T> for $t {
             type Output = Self;
-        
-            fn mul(self, rhs: Self) -> Self::Output {
-                Self(self.0.component_mul(&rhs.0))
+
+            fn $fn(self, rhs: T) -> Self::Output {
+                let rhs: f32 = rhs.as_();
+                let result = Self(self.0.map(|a| a $op rhs));
+                $crate::check_finite!(concat!(stringify!($t), " ", stringify!($op), " scalar"), result.0, self.0, rhs);
+
+                result
             }
         }
-        
-        impl Div for $t {
+
+        multi_impl!(for (i32, u32, i64, u64, f32, f64) {
+            impl $trait<$t> {
+                type Output = $t;
+
+                fn $fn(self, rhs: $t) -> Self::Output {
+                    let lhs: f32 = num::cast::AsPrimitive::as_(self);
+                    let result = $t(rhs.0.map(|b| lhs $op b));
+                    $crate::check_finite!(concat!("scalar ", stringify!($op), " ", stringify!($t)), result.0, lhs, rhs.0);
+
+                    result
+                }
+            }
+
+            impl $trait<&$t> {
+                type Output = $t;
+
+                fn $fn(self, rhs: &$t) -> Self::Output {
+                    self $op *rhs
+                }
+            }
+        });
+
+        impl<T: GLScalar> $trait<T> for &$t {
+            type Output = $t;
+
+            fn $fn(self, rhs: T) -> Self::Output {
+                *self $op rhs
+            }
+        }
+
+        impl<T: GLScalar> $assign_trait<T> for $t {
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+pub(crate) use matrix_arithmetic;
+
+/// `/` and `%` for the integer vectors: wrapping, and 0 for a zero divisor.
+pub(crate) trait IntegerDivision: Sized {
+    fn div_or_zero(self, rhs: Self) -> Self;
+    fn rem_or_zero(self, rhs: Self) -> Self;
+}
+
+macro_rules! integer_division {
+    ($($s:ty),+) => {
+        $(
+            impl IntegerDivision for $s {
+                fn div_or_zero(self, rhs: Self) -> Self {
+                    if rhs == 0 { 0 } else { self.wrapping_div(rhs) }
+                }
+
+                fn rem_or_zero(self, rhs: Self) -> Self {
+                    if rhs == 0 { 0 } else { self.wrapping_rem(rhs) }
+                }
+            }
+        )+
+    };
+}
+
+integer_division!(i32, u32);
+
+macro_rules! integer_arithmetic {
+    ($t:tt, $s:ty) => {
+        // Integer operators wrap on overflow, as they do in GLSL. GLSL leaves division by zero undefined; here `/` and
+        // `%` give 0 for a zero divisor rather than panicking.
+
+        integer_arithmetic!(@binary $t, $s, Add, add, AddAssign, add_assign, <$s>::wrapping_add);
+        integer_arithmetic!(@binary $t, $s, Sub, sub, SubAssign, sub_assign, <$s>::wrapping_sub);
+        integer_arithmetic!(@binary $t, $s, Mul, mul, MulAssign, mul_assign, <$s>::wrapping_mul);
+        integer_arithmetic!(@binary $t, $s, Div, div, DivAssign, div_assign, <$s as $crate::IntegerDivision>::div_or_zero);
+        integer_arithmetic!(@binary $t, $s, Rem, rem, RemAssign, rem_assign, <$s as $crate::IntegerDivision>::rem_or_zero);
+        integer_arithmetic!(@binary $t, $s, BitAnd, bitand, BitAndAssign, bitand_assign, <$s as BitAnd>::bitand);
+        integer_arithmetic!(@binary $t, $s, BitOr, bitor, BitOrAssign, bitor_assign, <$s as BitOr>::bitor);
+        integer_arithmetic!(@binary $t, $s, BitXor, bitxor, BitXorAssign, bitxor_assign, <$s as BitXor>::bitxor);
+
+        // Shift amounts are taken modulo the bit width; GLSL leaves larger shifts undefined.
+
+        impl Shl for $t {
             type Output = Self;
-        
-            fn div(self, rhs: Self) -> Self::Output {
-                Self(self.0.component_div(&rhs.0))
+
+            fn shl(self, rhs: Self) -> Self::Output {
+                Self(self.0.zip_map(&rhs.0, |a, b| a.wrapping_shl(b as u32)))
             }
         }
-        
-        impl AddAssign for $t {
-            fn add_assign(&mut self, rhs: Self) {
-                self.0 += rhs.0;
+
+        impl Shr for $t {
+            type Output = Self;
+
+            fn shr(self, rhs: Self) -> Self::Output {
+                Self(self.0.zip_map(&rhs.0, |a, b| a.wrapping_shr(b as u32)))
             }
         }
-        
-        impl SubAssign for $t {
-            fn sub_assign(&mut self, rhs: Self) {
-                self.0 -= rhs.0;
+
+        impl Shl<$s> for $t {
+            type Output = Self;
+
+            fn shl(self, rhs: $s) -> Self::Output {
+                Self(self.0.map(|a| a.wrapping_shl(rhs as u32)))
             }
         }
-        
-        impl MulAssign for $t {
-            fn mul_assign(&mut self, rhs: Self) {
-                self.0.component_mul_assign(&rhs.0);
+
+        impl Shr<$s> for $t {
+            type Output = Self;
+
+            fn shr(self, rhs: $s) -> Self::Output {
+                Self(self.0.map(|a| a.wrapping_shr(rhs as u32)))
+            }
+        }
+
+        impl ShlAssign for $t {
+            fn shl_assign(&mut self, rhs: Self) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl ShrAssign for $t {
+            fn shr_assign(&mut self, rhs: Self) {
+                *self = *self >> rhs;
             }
         }
-        
-        impl DivAssign for $t {
-            fn div_assign(&mut self, rhs: Self) {
-                self.0.component_div_assign(&rhs.0);
+
+        impl ShlAssign<$s> for $t {
+            fn shl_assign(&mut self, rhs: $s) {
+                *self = *self << rhs;
+            }
+        }
+
+        impl ShrAssign<$s> for $t {
+            fn shr_assign(&mut self, rhs: $s) {
+                *self = *self >> rhs;
             }
         }
-        
-        // Scalar-Vector Operators
-        
-        impl<T: GLScalar> Add<T> for $t {
+
+        impl Neg for $t {
             type Output = Self;
-        
-            fn add(self, rhs: T) -> Self::Output {
-                let rhs: f32 = rhs.as_();
-                Self(self.0.add_scalar(rhs))
+
+            fn neg(self) -> Self::Output {
+                Self(self.0.map(<$s>::wrapping_neg))
             }
         }
-        
-        impl<T: GLScalar> Sub<T> for $t {
+
+        impl Not for $t {
             type Output = Self;
-        
-            fn sub(self, rhs: T) -> Self::Output {
-                let rhs: f32 = rhs.as_();
-                Self(self.0.add_scalar(-rhs))
+
+            fn not(self) -> Self::Output {
+                Self(self.0.map(|a| !a))
+            }
+        }
+
+        impl Neg for &$t {
+            type Output = $t;
+
+            fn neg(self) -> Self::Output {
+                -*self
+            }
+        }
+
+        impl Not for &$t {
+            type Output = $t;
+
+            fn not(self) -> Self::Output {
+                !*self
             }
         }
-        
-        impl<T: GLScalar> Mul<T> for $t {
+    };
+    (@binary $t:tt, $s:ty, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:expr) => {
+        impl $trait for $t {
             type Output = Self;
-        
-            fn mul(self, rhs: T) -> Self::Output {
-                let rhs: f32 = rhs.as_();
-                Self(self.0 * rhs)
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self(self.0.zip_map(&rhs.0, |a, b| $op(a, b)))
             }
         }
-        
-        impl<T: GLScalar> Div<T> for $t {
+
+        impl $trait<$s> for $t {
             type Output = Self;
-        
-            fn div(self, rhs: T) -> Self::Output {
-                let rhs: f32 = rhs.as_();
-                Self(self.0 / rhs)
+
+            fn $fn(self, rhs: $s) -> Self::Output {
+                Self(self.0.map(|a| $op(a, rhs)))
             }
         }
-        
-        multi_impl! (Add<$t> for (i32, u32, i64, u64, f32, f64) {
+
+        impl $trait<$t> for $s {
             type Output = $t;
-        
-            fn add(self, rhs: $t) -> Self::Output {
-                rhs + self
+
+            fn $fn(self, rhs: $t) -> Self::Output {
+                $t(rhs.0.map(|b| $op(self, b)))
             }
-        });
-        
-        multi_impl! (Sub<$t> for (i32, u32, i64, u64, f32, f64) {
+        }
+
+        impl $assign_trait for $t {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = (*self).$fn(rhs);
+            }
+        }
+
+        impl $assign_trait<$s> for $t {
+            fn $assign_fn(&mut self, rhs: $s) {
+                *self = (*self).$fn(rhs);
+            }
+        }
+
+        impl $trait<&$t> for $t {
             type Output = $t;
-        
-            fn sub(self, rhs: $t) -> Self::Output {
-                rhs + self
+
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                self.$fn(*rhs)
             }
-        });
-        
-        multi_impl! (Mul<$t> for (i32, u32, i64, u64, f32, f64) {
+        }
+
+        impl $trait<$t> for &$t {
             type Output = $t;
-        
-            fn mul(self, rhs: $t) -> Self::Output {
-                rhs + self
+
+            fn $fn(self, rhs: $t) -> Self::Output {
+                (*self).$fn(rhs)
             }
-        });
+        }
+
+        impl $trait<&$t> for &$t {
+            type Output = $t;
+
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+
+        impl $trait<$s> for &$t {
+            type Output = $t;
+
+            fn $fn(self, rhs: $s) -> Self::Output {
+                (*self).$fn(rhs)
+            }
+        }
+
+        impl $trait<&$t> for $s {
+            type Output = $t;
+
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+
+        impl $assign_trait<&$t> for $t {
+            fn $assign_fn(&mut self, rhs: &$t) {
+                *self = (*self).$fn(*rhs);
+            }
+        }
     };
 }
 
-pub(crate) use matrix_arithmetic;
+pub(crate) use integer_arithmetic;
+
+macro_rules! vector_index {
+    ($t:tt, $s:ty) => {
+        impl Index<usize> for $t {
+            type Output = $s;
+
+            fn index(&self, index: usize) -> &$s {
+                &self.0[index]
+            }
+        }
+
+        impl IndexMut<usize> for $t {
+            fn index_mut(&mut self, index: usize) -> &mut $s {
+                &mut self.0[index]
+            }
+        }
+    };
+}
+
+pub(crate) use vector_index;
+
+macro_rules! matrix_access {
+    ($t:tt, $v:tt, $n:literal) => {
+        const _: () = assert!(std::mem::size_of::<$v>() == std::mem::size_of::<[f32; $n]>());
+
+        // Indexing a matrix gives a column, as in GLSL.
+        impl Index<usize> for $t {
+            type Output = $v;
+
+            fn index(&self, index: usize) -> &$v {
+                let column: &[f32; $n] = &self.0.data.0[index];
+
+                // SAFETY: the vector is a repr(C) wrapper around a repr(C) nalgebra matrix whose repr(transparent)
+                // storage is a single [f32; N] column, so it has the same layout as the column.
+                unsafe { &*(column as *const [f32; $n] as *const $v) }
+            }
+        }
+
+        impl IndexMut<usize> for $t {
+            fn index_mut(&mut self, index: usize) -> &mut $v {
+                let column: &mut [f32; $n] = &mut self.0.data.0[index];
+
+                // SAFETY: see `index`.
+                unsafe { &mut *(column as *mut [f32; $n] as *mut $v) }
+            }
+        }
+
+        impl $t {
+            pub fn column(&self, index: usize) -> $v {
+                self[index]
+            }
+
+            pub fn row(&self, index: usize) -> $v {
+                $v(self.0.row(index).transpose())
+            }
+
+            pub fn set_column(&mut self, index: usize, column: $v) {
+                self[index] = column;
+            }
+
+            pub fn set_row(&mut self, index: usize, row: $v) {
+                self.0.set_row(index, &row.0.transpose());
+            }
+        }
+    };
+}
+
+pub(crate) use matrix_access;
+
+// Matrices are read column by column, like their constructors.
+macro_rules! slice_conversion {
+    ($t:ty, $s:ty, $n:expr) => {
+        impl TryFrom<&[$s]> for $t {
+            type Error = $crate::GlTypesError;
+
+            fn try_from(slice: &[$s]) -> Result<Self, Self::Error> {
+                if slice.len() != $n {
+                    return Err($crate::GlTypesError::WrongLength { expected: $n, found: slice.len() });
+                }
+
+                let mut value = <$t>::ZERO;
+                value.0.as_mut_slice().copy_from_slice(slice);
+                $crate::check_finite!(concat!(stringify!($t), "::try_from"), value.0);
+
+                Ok(value)
+            }
+        }
+    };
+}
+
+pub(crate) use slice_conversion;
+
+// Implemented per scalar type rather than for `T: GLScalar`, so that it doesn't overlap with the tuple constructors.
+macro_rules! scalar_constructor {
+    ($t:tt, $trait:ident) => {
+        scalar_constructor!(@impl $t, $trait, i32, u32, i64, u64, f32, f64);
+    };
+    (@impl $t:tt, $trait:ident, $($s:ty),+) => {
+        $(
+            impl $trait<$s> for $t {
+                fn new(args: $s) -> $t {
+                    let args: f32 = num::cast::AsPrimitive::as_(args);
+                    $crate::check_finite!(concat!(stringify!($t), " constructor"), args);
+
+                    $t::IDENTITY * args
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use scalar_constructor;
+
+/// Used by the constructor macros to pick the const path when every argument is a (possibly negated) literal.
+///
+/// Calls `$crate::$callback!(@literals (a) (b) ...)` if so, and `$crate::$callback!(@exprs ...)` with the original
+/// arguments otherwise. A leading `-` is checked separately because a `literal` fragment that starts with `-` can't
+/// back out once it fails. Negative literals only take the const path for `signed` callers, since casting one to an
+/// unsigned type doesn't compile.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __literal_args {
+    ($callback:ident $sign:ident [$($lits:tt)*] [$($args:tt)*]) => {
+        $crate::$callback!(@literals $($lits)*)
+    };
+    ($callback:ident signed [$($lits:tt)*] [$($args:tt)*] - $l:literal $(, $($rest:tt)*)?) => {
+        $crate::__literal_args!($callback signed [$($lits)* (-$l)] [$($args)*] $($($rest)*)?)
+    };
+    ($callback:ident $sign:ident [$($lits:tt)*] [$($args:tt)*] - $($rest:tt)*) => {
+        $crate::$callback!(@exprs $($args)*)
+    };
+    ($callback:ident $sign:ident [$($lits:tt)*] [$($args:tt)*] $l:literal $(, $($rest:tt)*)?) => {
+        $crate::__literal_args!($callback $sign [$($lits)* ($l)] [$($args)*] $($($rest)*)?)
+    };
+    ($callback:ident $sign:ident [$($lits:tt)*] [$($args:tt)*] $($rest:tt)*) => {
+        $crate::$callback!(@exprs $($args)*)
+    };
+}
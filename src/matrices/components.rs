@@ -0,0 +1,116 @@
+//! Mixed scalar and vector arguments for the matrix constructors, such as `mat3!(vec2, 1.0, vec4, 2, 3)`.
+//!
+//! As in GLSL, components are consumed in order and fill the matrix column by column. There must be enough
+//! components to fill the matrix, and every argument must contribute at least one. Both rules are checked at
+//! compile time.
+//!
+//! ```compile_fail
+//! // Three components can't fill a Mat2
+//! let m = gl_types::mat2!(1, 2, 3);
+//! ```
+//!
+//! ```compile_fail
+//! // The Vec2 would not be used
+//! let m = gl_types::mat2!(gl_types::vec4!(1), gl_types::vec2!(2));
+//! ```
+
+use crate::{
+    private::Seal,
+    vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4, VecN},
+    GLScalar,
+};
+
+/// A scalar or vector that can be one argument of a matrix constructor.
+pub trait MatrixArg: Seal + Copy {
+    const COUNT: usize;
+
+    /// Writes as many components as fit in `out`.
+    fn write(self, out: &mut [f32]);
+}
+
+/// A tuple of [`MatrixArg`]s.
+pub trait MatrixArgs: Seal {
+    /// The component count of each argument.
+    const COUNTS: &'static [usize];
+
+    fn write(self, out: &mut [f32]);
+}
+
+impl<S: GLScalar> MatrixArg for S {
+    const COUNT: usize = 1;
+
+    fn write(self, out: &mut [f32]) {
+        out[0] = self.as_();
+    }
+}
+
+macro_rules! vector_arg {
+    ($t:ty, $n:literal) => {
+        impl MatrixArg for $t {
+            const COUNT: usize = $n;
+
+            fn write(self, out: &mut [f32]) {
+                let n = out.len().min($n);
+                self.as_array().iter().zip(&mut out[..n]).for_each(|(c, o)| *o = *c as f32);
+            }
+        }
+    };
+}
+
+vector_arg!(Vec2, 2);
+vector_arg!(Vec3, 3);
+vector_arg!(Vec4, 4);
+vector_arg!(IVec2, 2);
+vector_arg!(IVec3, 3);
+vector_arg!(IVec4, 4);
+vector_arg!(UVec2, 2);
+vector_arg!(UVec3, 3);
+vector_arg!(UVec4, 4);
+
+macro_rules! matrix_args {
+    ($head:ident $(, $tail:ident)*) => {
+        impl<$head: MatrixArg, $($tail: MatrixArg),*> Seal for ($head, $($tail,)*) {}
+
+        impl<$head: MatrixArg, $($tail: MatrixArg),*> MatrixArgs for ($head, $($tail,)*) {
+            const COUNTS: &'static [usize] = &[$head::COUNT, $($tail::COUNT),*];
+
+            #[allow(non_snake_case)]
+            fn write(self, out: &mut [f32]) {
+                let ($head, $($tail,)*) = self;
+                let mut i = 0;
+
+                $head.write(&mut out[i..]);
+                i += $head::COUNT;
+                $(
+                    $tail.write(&mut out[i..]);
+                    i += $tail::COUNT;
+                )*
+
+                let _ = i;
+            }
+        }
+
+        matrix_args!($($tail),*);
+    };
+    () => {};
+}
+
+matrix_args!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+/// Checks GLSL's argument rules for a constructor that needs `needed` components.
+pub(crate) const fn check_args(counts: &[usize], needed: usize) {
+    let mut before_last = 0;
+    let mut i = 0;
+    while i + 1 < counts.len() {
+        before_last += counts[i];
+        i += 1;
+    }
+
+    if before_last + counts[counts.len() - 1] < needed {
+        panic!("not enough components in the constructor arguments");
+    }
+
+    if before_last >= needed {
+        panic!("too many constructor arguments: the last argument would not be used");
+    }
+}
@@ -1,9 +1,10 @@
-use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign}};
+use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 
 use multi_impl::multi_impl;
-use nalgebra::{Matrix2, Vector2};
+use nalgebra::{ArrayStorage, Matrix2, Vector2};
 
-use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, private::Seal, vectors::Vec2, GLScalar, Make};
+use super::components::{check_args, MatrixArgs};
+use crate::{inner_matrix::InnerMatrix, matrix_access, matrix_arithmetic, check_finite, check_finite_const, private::Seal, scalar_constructor, slice_conversion, vectors::Vec2, GLScalar, Make};
 
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
@@ -19,40 +20,79 @@ impl Mat2 {
     pub const ZERO: Mat2 = Mat2::_new(0.0, 0.0, 0.0, 0.0);
     pub const IDENTITY: Mat2 = Mat2::_new(1.0, 0.0, 0.0, 1.0);
 
-    pub(in crate) const fn _new(m11: f32, m12: f32, m21: f32, m22: f32) -> Self {
-        Self(Matrix2::new(m11, m12, m21, m22))
+    /// Takes the components in column-major order, as GLSL does: `m01` is column 0, row 1.
+    pub(in crate) const fn _new(m00: f32, m01: f32, m10: f32, m11: f32) -> Self {
+        // nalgebra's `new` takes its arguments row by row
+        Self(Matrix2::new(m00, m10, m01, m11))
+    }
+
+    /// Takes the components in column-major order, like `mat2!`. Usable in const items.
+    pub const fn new_const(m00: f32, m01: f32, m10: f32, m11: f32) -> Self {
+        check_finite_const!("Mat2::new_const", m00, m01, m10, m11);
+
+        Self::_new(m00, m01, m10, m11)
+    }
+
+    pub const fn from_cols_const(c0: crate::vectors::Vec2, c1: crate::vectors::Vec2) -> Self {
+        Self(Matrix2::from_array_storage(ArrayStorage([c0.0.data.0[0], c1.0.data.0[0]])))
+    }
+
+    pub fn from_cols(c0: Vec2, c1: Vec2) -> Self {
+        let result = Self(Matrix2::from_columns(&[c0.0, c1.0]));
+        check_finite!("Mat2::from_cols", result.0);
+
+        result
+    }
+
+    pub fn from_rows(r0: Vec2, r1: Vec2) -> Self {
+        let result = Self::_from_rows(r0, r1);
+        check_finite!("Mat2::from_rows", result.0);
+
+        result
+    }
+
+    /// [`Self::from_rows`] without the `nan-checks` check, for functions that check their own result.
+    pub(in crate) fn _from_rows(r0: Vec2, r1: Vec2) -> Self {
+        Self(Matrix2::from_rows(&[r0.0.transpose(), r1.0.transpose()]))
     }
 }
 
-matrix_arithmetic!(Mat2);
+matrix_arithmetic!(Mat2, Vec2);
+matrix_access!(Mat2, Vec2, 2);
+slice_conversion!(Mat2, f32, 4);
 
 impl Seal for Mat2 {}
 
 pub trait Mat2Constructor<T>: Seal {
-    fn new(args: T) -> Mat2;
+    fn new(args: T) -> Self;
 }
 
-impl<A: GLScalar> Mat2Constructor<A> for Mat2 {
-    fn new(args: A) -> Mat2 {
-        Mat2::_new(args.as_(), 0.0, 0.0, args.as_())
-    }
-}
+scalar_constructor!(Mat2, Mat2Constructor);
 
 impl Mat2Constructor<Vec2> for Mat2 {
     fn new(args: Vec2) -> Mat2 {
+        check_finite!("Mat2 constructor", args.0);
+
         Self(Matrix2::from_diagonal(&args.0))
     }
 }
 
-impl Mat2Constructor<(Vec2, Vec2)> for Mat2 {
-    fn new(args: (Vec2, Vec2)) -> Mat2 {
-        Self(Matrix2::from_columns(&[args.0.0, args.1.0]))
+
+impl Mat2Constructor<Mat2> for Mat2 {
+    fn new(args: Mat2) -> Mat2 {
+        args
     }
 }
 
-impl<A: GLScalar, B: GLScalar, C: GLScalar, D: GLScalar> Mat2Constructor<(A, B, C, D)> for Mat2 {
-    fn new(args: (A, B, C, D)) -> Mat2 {
-        Mat2::_new(args.0.as_(), args.1.as_(), args.2.as_(), args.3.as_())
+impl<T: MatrixArgs> Mat2Constructor<T> for Mat2 {
+    fn new(args: T) -> Mat2 {
+        const { check_args(T::COUNTS, 4) };
+
+        let mut m = [0.0; 4];
+        args.write(&mut m);
+        check_finite!("Mat2 constructor", m);
+
+        Self(Matrix2::from_column_slice(&m))
     }
 }
 
@@ -60,7 +100,7 @@ impl Mat2Constructor<super::Mat3> for Mat2 {
     fn new(args: super::Mat3) -> Mat2 {
         let cols: Vec<_> = args.0.column_iter().map(|col| Vector2::new(col[0], col[1])).collect();
 
-        Self(Matrix2::from_columns(&cols[0..1]))
+        Self(Matrix2::from_columns(&cols[0..2]))
     }
 }
 
@@ -68,36 +108,39 @@ impl Mat2Constructor<super::Mat4> for Mat2 {
     fn new(args: super::Mat4) -> Mat2 {
         let cols: Vec<_> = args.0.column_iter().map(|col| Vector2::new(col[0], col[1])).collect();
 
-        Self(Matrix2::from_columns(&cols[0..1]))
+        Self(Matrix2::from_columns(&cols[0..2]))
     }
 }
 
 #[macro_export]
 macro_rules! mat2 {
-    ($a:expr, $b:expr, $c:expr, $d:expr) => {
-        {
-            use $crate::matrices::Mat2Constructor;
-            $crate::matrices::Mat2::new(($a, $b, $c, $d))
-        }
+    () => {
+        $crate::matrices::Mat2::ZERO
     };
-    ($a:expr, $b:expr) => {
-        {
-            use $crate::matrices::Mat2Constructor;
-            $crate::matrices::Mat2::new(($a, $b))
-        }
+    (@literals ($m00:expr) ($m01:expr) ($m10:expr) ($m11:expr)) => {
+        $crate::matrices::Mat2::new_const(($m00) as f32, ($m01) as f32, ($m10) as f32, ($m11) as f32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::matrices::Mat2::new_const(($a) as f32, 0.0, 0.0, ($a) as f32)
     };
-    ($a:expr) => {
+    (@literals $($a:tt)*) => {
+        $crate::mat2!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
         {
             use $crate::matrices::Mat2Constructor;
             $crate::matrices::Mat2::new($a)
         }
     };
-    () => {
+    (@exprs $($a:expr),+ $(,)?) => {
         {
             use $crate::matrices::Mat2Constructor;
-            $crate::matrices::Mat2::new(0)
+            $crate::matrices::Mat2::new(($($a,)+))
         }
     };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(mat2 signed [] [$($args)+] $($args)+)
+    };
 }
 
 impl InnerMatrix<2, 2> for Mat2 {
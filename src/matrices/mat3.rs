@@ -1,9 +1,10 @@
-use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign}};
+use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 
 use multi_impl::multi_impl;
-use nalgebra::{Matrix3, Vector3};
+use nalgebra::{ArrayStorage, Matrix3, Vector3};
 
-use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, private::Seal, vectors::Vec3, GLScalar, Make};
+use super::components::{check_args, MatrixArgs};
+use crate::{inner_matrix::InnerMatrix, matrix_access, matrix_arithmetic, check_finite, check_finite_const, private::Seal, scalar_constructor, slice_conversion, vectors::Vec3, GLScalar, Make};
 
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
@@ -19,48 +20,81 @@ impl Mat3 {
     pub const ZERO: Mat3 = Mat3::_new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
     pub const IDENTITY: Mat3 = Mat3::_new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
 
-    pub(in crate) const fn _new(m11: f32, m12: f32, m13: f32, m21: f32, m22: f32, m23: f32, m31: f32, m32: f32, m33: f32) -> Self {
-        Self(Matrix3::new(m11, m12, m13, m21, m22, m23, m31, m32, m33))
+    /// Takes the components in column-major order, as GLSL does: `m01` is column 0, row 1.
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate) const fn _new(m00: f32, m01: f32, m02: f32, m10: f32, m11: f32, m12: f32, m20: f32, m21: f32, m22: f32) -> Self {
+        // nalgebra's `new` takes its arguments row by row
+        Self(Matrix3::new(m00, m10, m20, m01, m11, m21, m02, m12, m22))
+    }
+
+    /// Takes the components in column-major order, like `mat3!`. Usable in const items.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new_const(m00: f32, m01: f32, m02: f32, m10: f32, m11: f32, m12: f32, m20: f32, m21: f32, m22: f32) -> Self {
+        check_finite_const!("Mat3::new_const", m00, m01, m02, m10, m11, m12, m20, m21, m22);
+
+        Self::_new(m00, m01, m02, m10, m11, m12, m20, m21, m22)
+    }
+
+    pub const fn from_cols_const(c0: crate::vectors::Vec3, c1: crate::vectors::Vec3, c2: crate::vectors::Vec3) -> Self {
+        Self(Matrix3::from_array_storage(ArrayStorage([c0.0.data.0[0], c1.0.data.0[0], c2.0.data.0[0]])))
+    }
+
+    pub fn from_cols(c0: Vec3, c1: Vec3, c2: Vec3) -> Self {
+        let result = Self(Matrix3::from_columns(&[c0.0, c1.0, c2.0]));
+        check_finite!("Mat3::from_cols", result.0);
+
+        result
+    }
+
+    pub fn from_rows(r0: Vec3, r1: Vec3, r2: Vec3) -> Self {
+        let result = Self::_from_rows(r0, r1, r2);
+        check_finite!("Mat3::from_rows", result.0);
+
+        result
+    }
+
+    /// [`Self::from_rows`] without the `nan-checks` check, for functions that check their own result.
+    pub(in crate) fn _from_rows(r0: Vec3, r1: Vec3, r2: Vec3) -> Self {
+        Self(Matrix3::from_rows(&[r0.0.transpose(), r1.0.transpose(), r2.0.transpose()]))
     }
 }
 
-matrix_arithmetic!(Mat3);
+matrix_arithmetic!(Mat3, Vec3);
+matrix_access!(Mat3, Vec3, 3);
+slice_conversion!(Mat3, f32, 9);
 
 impl Seal for Mat3 {}
 
 pub trait Mat3Constructor<T>: Seal {
-    fn new(args: T) -> Mat3;
+    fn new(args: T) -> Self;
 }
 
-impl<A: GLScalar> Mat3Constructor<A> for Mat3 {
-    fn new(args: A) -> Mat3 {
-        Mat3::_new(
-            args.as_(), 0.0, 0.0,
-            0.0, args.as_(), 0.0,
-            0.0, 0.0, args.as_())
-    }
-}
+scalar_constructor!(Mat3, Mat3Constructor);
 
 impl Mat3Constructor<Vec3> for Mat3 {
     fn new(args: Vec3) -> Mat3 {
+        check_finite!("Mat3 constructor", args.0);
+
         Self(Matrix3::from_diagonal(&args.0))
     }
 }
 
-impl Mat3Constructor<(Vec3, Vec3, Vec3)> for Mat3 {
-    fn new(args: (Vec3, Vec3, Vec3)) -> Mat3 {
-        Self(Matrix3::from_columns(&[args.0.0, args.1.0, args.2.0]))
+
+impl Mat3Constructor<Mat3> for Mat3 {
+    fn new(args: Mat3) -> Mat3 {
+        args
     }
 }
 
-impl<A, B, C, D, E, F, G, H, I> Mat3Constructor<(A, B, C, D, E, F, G, H, I)> for Mat3 
-where 
-    A: GLScalar, B: GLScalar, C: GLScalar,
-    D: GLScalar, E: GLScalar, F: GLScalar,
-    G: GLScalar, H: GLScalar, I: GLScalar
-        {
-    fn new(args: (A, B, C, D, E, F, G, H, I)) -> Mat3 {
-        Mat3::_new(args.0.as_(), args.1.as_(), args.2.as_(), args.3.as_(), args.4.as_(), args.5.as_(), args.6.as_(), args.7.as_(), args.8.as_())
+impl<T: MatrixArgs> Mat3Constructor<T> for Mat3 {
+    fn new(args: T) -> Mat3 {
+        const { check_args(T::COUNTS, 9) };
+
+        let mut m = [0.0; 9];
+        args.write(&mut m);
+        check_finite!("Mat3 constructor", m);
+
+        Self(Matrix3::from_column_slice(&m))
     }
 }
 
@@ -77,36 +111,39 @@ impl Mat3Constructor<super::Mat4> for Mat3 {
     fn new(args: super::Mat4) -> Mat3 {
         let cols: Vec<_> = args.0.column_iter().map(|col| Vector3::new(col[0], col[1], col[2])).collect();
 
-        Self(Matrix3::from_columns(&cols[0..2]))
+        Self(Matrix3::from_columns(&cols[0..3]))
     }
 }
 
 #[macro_export]
 macro_rules! mat3 {
-    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr) => {
-        {
-            use $crate::matrices::Mat3Constructor;
-            $crate::matrices::Mat3::new(($a, $b, $c, $d, $e, $f, $g, $h, $i))
-        }
+    () => {
+        $crate::matrices::Mat3::ZERO
     };
-    ($a:expr, $b:expr, $c:expr) => {
-        {
-            use $crate::matrices::Mat3Constructor;
-            $crate::matrices::Mat3::new(($a, $b, $c))
-        }
+    (@literals ($m00:expr) ($m01:expr) ($m02:expr) ($m10:expr) ($m11:expr) ($m12:expr) ($m20:expr) ($m21:expr) ($m22:expr)) => {
+        $crate::matrices::Mat3::new_const(($m00) as f32, ($m01) as f32, ($m02) as f32, ($m10) as f32, ($m11) as f32, ($m12) as f32, ($m20) as f32, ($m21) as f32, ($m22) as f32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::matrices::Mat3::new_const(($a) as f32, 0.0, 0.0, 0.0, ($a) as f32, 0.0, 0.0, 0.0, ($a) as f32)
     };
-    ($a:expr) => {
+    (@literals $($a:tt)*) => {
+        $crate::mat3!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
         {
             use $crate::matrices::Mat3Constructor;
             $crate::matrices::Mat3::new($a)
         }
     };
-    () => {
+    (@exprs $($a:expr),+ $(,)?) => {
         {
             use $crate::matrices::Mat3Constructor;
-            $crate::matrices::Mat3::new(0)
+            $crate::matrices::Mat3::new(($($a,)+))
         }
     };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(mat3 signed [] [$($args)+] $($args)+)
+    };
 }
 
 impl InnerMatrix<3, 3> for Mat3 {
@@ -1,9 +1,10 @@
-use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign}};
+use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 
 use multi_impl::multi_impl;
-use nalgebra::{Matrix4, Vector4};
+use nalgebra::{ArrayStorage, Matrix4, Vector4};
 
-use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, private::Seal, vectors::Vec4, GLScalar, Make};
+use super::components::{check_args, MatrixArgs};
+use crate::{inner_matrix::InnerMatrix, matrix_access, matrix_arithmetic, check_finite, check_finite_const, private::Seal, scalar_constructor, slice_conversion, vectors::Vec4, GLScalar, Make};
 
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
@@ -19,51 +20,81 @@ impl Mat4 {
     pub const ZERO: Mat4 = Mat4::_new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
     pub const IDENTITY: Mat4 = Mat4::_new(1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0);
 
-    pub(in crate) const fn _new(m11: f32, m12: f32, m13: f32, m14: f32, m21: f32, m22: f32, m23: f32, m24: f32, m31: f32, m32: f32, m33: f32, m34: f32, m41: f32, m42: f32, m43: f32, m44: f32) -> Self {
-        Self(Matrix4::new(m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44))
+    /// Takes the components in column-major order, as GLSL does: `m01` is column 0, row 1.
+    #[allow(clippy::too_many_arguments)]
+    pub(in crate) const fn _new(m00: f32, m01: f32, m02: f32, m03: f32, m10: f32, m11: f32, m12: f32, m13: f32, m20: f32, m21: f32, m22: f32, m23: f32, m30: f32, m31: f32, m32: f32, m33: f32) -> Self {
+        // nalgebra's `new` takes its arguments row by row
+        Self(Matrix4::new(m00, m10, m20, m30, m01, m11, m21, m31, m02, m12, m22, m32, m03, m13, m23, m33))
+    }
+
+    /// Takes the components in column-major order, like `mat4!`. Usable in const items.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new_const(m00: f32, m01: f32, m02: f32, m03: f32, m10: f32, m11: f32, m12: f32, m13: f32, m20: f32, m21: f32, m22: f32, m23: f32, m30: f32, m31: f32, m32: f32, m33: f32) -> Self {
+        check_finite_const!("Mat4::new_const", m00, m01, m02, m03, m10, m11, m12, m13, m20, m21, m22, m23, m30, m31, m32, m33);
+
+        Self::_new(m00, m01, m02, m03, m10, m11, m12, m13, m20, m21, m22, m23, m30, m31, m32, m33)
+    }
+
+    pub const fn from_cols_const(c0: crate::vectors::Vec4, c1: crate::vectors::Vec4, c2: crate::vectors::Vec4, c3: crate::vectors::Vec4) -> Self {
+        Self(Matrix4::from_array_storage(ArrayStorage([c0.0.data.0[0], c1.0.data.0[0], c2.0.data.0[0], c3.0.data.0[0]])))
+    }
+
+    pub fn from_cols(c0: Vec4, c1: Vec4, c2: Vec4, c3: Vec4) -> Self {
+        let result = Self(Matrix4::from_columns(&[c0.0, c1.0, c2.0, c3.0]));
+        check_finite!("Mat4::from_cols", result.0);
+
+        result
+    }
+
+    pub fn from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Self {
+        let result = Self::_from_rows(r0, r1, r2, r3);
+        check_finite!("Mat4::from_rows", result.0);
+
+        result
+    }
+
+    /// [`Self::from_rows`] without the `nan-checks` check, for functions that check their own result.
+    pub(in crate) fn _from_rows(r0: Vec4, r1: Vec4, r2: Vec4, r3: Vec4) -> Self {
+        Self(Matrix4::from_rows(&[r0.0.transpose(), r1.0.transpose(), r2.0.transpose(), r3.0.transpose()]))
     }
 }
 
-matrix_arithmetic!(Mat4);
+matrix_arithmetic!(Mat4, Vec4);
+matrix_access!(Mat4, Vec4, 4);
+slice_conversion!(Mat4, f32, 16);
 
 impl Seal for Mat4 {}
 
 pub trait Mat4Constructor<T>: Seal {
-    fn new(args: T) -> Mat4;
+    fn new(args: T) -> Self;
 }
 
-impl<A: GLScalar> Mat4Constructor<A> for Mat4 {
-    fn new(args: A) -> Mat4 {
-        Mat4::_new(
-            args.as_(), 0.0, 0.0, 0.0,
-            0.0, args.as_(), 0.0, 0.0,
-            0.0, 0.0, args.as_(), 0.0,
-            0.0, 0.0, 0.0, args.as_()
-        )
-    }
-}
+scalar_constructor!(Mat4, Mat4Constructor);
 
 impl Mat4Constructor<Vec4> for Mat4 {
     fn new(args: Vec4) -> Mat4 {
+        check_finite!("Mat4 constructor", args.0);
+
         Self(Matrix4::from_diagonal(&args.0))
     }
 }
 
-impl Mat4Constructor<(Vec4, Vec4, Vec4, Vec4)> for Mat4 {
-    fn new(args: (Vec4, Vec4, Vec4, Vec4)) -> Mat4 {
-        Self(Matrix4::from_columns(&[args.0.0, args.1.0, args.2.0, args.3.0]))
+
+impl Mat4Constructor<Mat4> for Mat4 {
+    fn new(args: Mat4) -> Mat4 {
+        args
     }
 }
 
-impl<A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P> Mat4Constructor<(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P)> for Mat4 
-where 
-    A: GLScalar, B: GLScalar, C: GLScalar, D: GLScalar,
-    E: GLScalar, F: GLScalar, G: GLScalar, H: GLScalar,
-    I: GLScalar, J: GLScalar, K: GLScalar, L: GLScalar,
-    M: GLScalar, N: GLScalar, O: GLScalar, P: GLScalar
-        {
-    fn new(args: (A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P)) -> Mat4 {
-        Mat4::_new(args.0.as_(), args.1.as_(), args.2.as_(), args.3.as_(), args.4.as_(), args.5.as_(), args.6.as_(), args.7.as_(), args.8.as_(), args.9.as_(), args.10.as_(), args.11.as_(), args.12.as_(), args.13.as_(), args.14.as_(), args.15.as_())
+impl<T: MatrixArgs> Mat4Constructor<T> for Mat4 {
+    fn new(args: T) -> Mat4 {
+        const { check_args(T::COUNTS, 16) };
+
+        let mut m = [0.0; 16];
+        args.write(&mut m);
+        check_finite!("Mat4 constructor", m);
+
+        Self(Matrix4::from_column_slice(&m))
     }
 }
 
@@ -88,30 +119,33 @@ impl Mat4Constructor<super::Mat3> for Mat4 {
 
 #[macro_export]
 macro_rules! mat4 {
-    ($a:expr, $b:expr, $c:expr, $d:expr, $e:expr, $f:expr, $g:expr, $h:expr, $i:expr, $j:expr, $k:expr, $l:expr, $m:expr, $n:expr, $o:expr, $p:expr) => {
-        {
-            use $crate::matrices::Mat4Constructor;
-            $crate::matrices::Mat4::new(($a, $b, $c, $d, $e, $f, $g, $h, $i, $j, $k, $l, $m, $n, $o, $p))
-        }
+    () => {
+        $crate::matrices::Mat4::ZERO
     };
-    ($a:expr, $b:expr, $c:expr, $d:expr) => {
-        {
-            use $crate::matrices::Mat4Constructor;
-            $crate::matrices::Mat4::new(($a, $b, $c, $d))
-        }
+    (@literals ($m00:expr) ($m01:expr) ($m02:expr) ($m03:expr) ($m10:expr) ($m11:expr) ($m12:expr) ($m13:expr) ($m20:expr) ($m21:expr) ($m22:expr) ($m23:expr) ($m30:expr) ($m31:expr) ($m32:expr) ($m33:expr)) => {
+        $crate::matrices::Mat4::new_const(($m00) as f32, ($m01) as f32, ($m02) as f32, ($m03) as f32, ($m10) as f32, ($m11) as f32, ($m12) as f32, ($m13) as f32, ($m20) as f32, ($m21) as f32, ($m22) as f32, ($m23) as f32, ($m30) as f32, ($m31) as f32, ($m32) as f32, ($m33) as f32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::matrices::Mat4::new_const(($a) as f32, 0.0, 0.0, 0.0, 0.0, ($a) as f32, 0.0, 0.0, 0.0, 0.0, ($a) as f32, 0.0, 0.0, 0.0, 0.0, ($a) as f32)
     };
-    ($a:expr) => {
+    (@literals $($a:tt)*) => {
+        $crate::mat4!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
         {
             use $crate::matrices::Mat4Constructor;
             $crate::matrices::Mat4::new($a)
         }
     };
-    () => {
+    (@exprs $($a:expr),+ $(,)?) => {
         {
             use $crate::matrices::Mat4Constructor;
-            $crate::matrices::Mat4::new(0)
+            $crate::matrices::Mat4::new(($($a,)+))
         }
     };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(mat4 signed [] [$($args)+] $($args)+)
+    };
 }
 
 impl InnerMatrix<4, 4> for Mat4 {
@@ -1,13 +1,15 @@
 mod mat2;
 mod mat3;
 mod mat4;
+mod components;
 
 pub use mat2::*;
 pub use mat3::*;
 pub use mat4::*;
+pub use components::{MatrixArg, MatrixArgs};
 use nalgebra::{ArrayStorage, Const, Matrix};
 
-use crate::{inner_matrix::InnerMatrix, Make};
+use crate::{check_finite, inner_matrix::InnerMatrix, Make};
 
 pub trait MatN<const N: usize>: InnerMatrix<N, N> + Make<Matrix<f32, Const<N>, Const<N>, ArrayStorage<f32, N, N>>> + AsRef<Self> {
     fn as_array(self) -> [[f32; N]; N];
@@ -25,6 +27,8 @@ impl<const N: usize, T: InnerMatrix<N, N> + Make<Matrix<f32, Const<N>, Const<N>,
     }
     
     fn from_array(array: [[f32; N]; N]) -> Self {
+        check_finite!("MatN::from_array", array);
+
         Self::make(Matrix::<f32, Const<N>, Const<N>, ArrayStorage<f32, N, N>>::from_data(
             ArrayStorage::<f32, N, N>(array)
         ))
@@ -39,6 +43,8 @@ impl<const N: usize, T: InnerMatrix<N, N> + Make<Matrix<f32, Const<N>, Const<N>,
     }
     
     fn from_slice(slice: &[[f32; N]; N]) -> Self {
+        check_finite!("MatN::from_slice", slice);
+
         Self::make(Matrix::<f32, Const<N>, Const<N>, ArrayStorage<f32, N, N>>::from_data(
             ArrayStorage::<f32, N, N>(slice.to_owned())
         ))
@@ -0,0 +1,111 @@
+//! Support for the `nan-checks` feature, which panics as soon as a constructor, operator or function produces a NaN or
+//! infinite component. See `check_finite!`.
+
+use nalgebra::SMatrix;
+
+/// Whether every component is finite, usable in const fns where iterators aren't.
+pub(crate) const fn all_finite(components: &[f32]) -> bool {
+    let mut i = 0;
+    while i < components.len() {
+        if !components[i].is_finite() {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// The components of a value, flattened so they can be checked and reported.
+pub(crate) trait Components {
+    fn components(&self) -> Vec<f32>;
+
+    fn all_finite(&self) -> bool {
+        self.components().iter().all(|c| c.is_finite())
+    }
+}
+
+impl Components for f32 {
+    fn components(&self) -> Vec<f32> {
+        vec![*self]
+    }
+
+    fn all_finite(&self) -> bool {
+        self.is_finite()
+    }
+}
+
+impl<const R: usize, const C: usize> Components for SMatrix<f32, R, C> {
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
+
+    fn all_finite(&self) -> bool {
+        self.iter().all(|c| c.is_finite())
+    }
+}
+
+// Integer vectors can't hold NaN, but implementing this lets code generic over the element type check them anyway.
+macro_rules! integer_components {
+    ($($s:ty),+) => {
+        $(
+            impl<const R: usize, const C: usize> Components for SMatrix<$s, R, C> {
+                fn components(&self) -> Vec<f32> {
+                    self.iter().map(|c| *c as f32).collect()
+                }
+
+                fn all_finite(&self) -> bool {
+                    true
+                }
+            }
+        )+
+    };
+}
+
+integer_components!(i32, u32);
+
+impl<const N: usize> Components for [f32; N] {
+    fn components(&self) -> Vec<f32> {
+        self.to_vec()
+    }
+}
+
+impl<const N: usize, const M: usize> Components for [[f32; N]; M] {
+    fn components(&self) -> Vec<f32> {
+        self.concat()
+    }
+}
+
+impl Components for Vec<f32> {
+    fn components(&self) -> Vec<f32> {
+        self.clone()
+    }
+}
+
+impl<T: Components + ?Sized> Components for &T {
+    fn components(&self) -> Vec<f32> {
+        (**self).components()
+    }
+
+    fn all_finite(&self) -> bool {
+        (**self).all_finite()
+    }
+}
+
+/// The components of an [`ElementWise`](crate::ElementWise) value, for functions that are generic over it.
+pub(crate) fn elements<const R: usize, const C: usize, T: crate::ElementWise<R, C>>(value: T) -> Vec<f32> {
+    let mut components = Vec::with_capacity(R * C);
+    value.operate(|c| components.push(*c));
+
+    components
+}
+
+#[cold]
+#[track_caller]
+pub(crate) fn report(operation: &str, result: Vec<f32>, operands: Vec<Vec<f32>>) -> ! {
+    if operands.is_empty() {
+        panic!("{} produced a non-finite result {:?}", operation, result)
+    } else {
+        panic!("{} produced a non-finite result {:?} from operands {:?}", operation, result, operands)
+    }
+}
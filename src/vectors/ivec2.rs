@@ -0,0 +1,99 @@
+use std::{fmt::Debug, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use nalgebra::Vector2;
+use vec_constructors::generate_constructors;
+
+use crate::{integer_arithmetic, private::Seal, slice_conversion, vector_index};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+pub struct IVec2(pub(in crate) Vector2<i32>);
+
+impl Debug for IVec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl IVec2 {
+    pub const ZERO: IVec2 = IVec2::_new(0, 0);
+    pub const ONE: IVec2 = IVec2::_new(1, 1);
+
+    pub(in crate) const fn _new(x: i32, y: i32) -> Self {
+        Self(Vector2::new(x, y))
+    }
+
+    /// Like `ivec2!`, but usable in const items.
+    pub const fn new_const(x: i32, y: i32) -> Self {
+        Self::_new(x, y)
+    }
+
+    pub fn as_array(self) -> [i32; 2] {
+        self.0.data.0[0]
+    }
+
+    pub fn from_array(array: [i32; 2]) -> Self {
+        Self(Vector2::from(array))
+    }
+
+    pub fn as_slice(&self) -> &[i32; 2] {
+        &self.0.data.0[0]
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [i32; 2] {
+        &mut self.0.data.0[0]
+    }
+}
+
+integer_arithmetic!(IVec2, i32);
+vector_index!(IVec2, i32);
+slice_conversion!(IVec2, i32, 2);
+
+impl Seal for IVec2 {}
+
+#[diagnostic::on_unimplemented(
+    message = "`IVec2` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 2 components, and every argument must contribute at least one"
+)]
+pub trait IVec2Constructor<T>: Seal {
+    fn new(args: T) -> Self;
+}
+
+generate_constructors!(IVec2, IVec2Constructor, i32, 2, [Vec, IVec, UVec]);
+
+#[macro_export]
+macro_rules! ivec2 {
+    () => {
+        $crate::vectors::IVec2::ZERO
+    };
+    (@literals ($x:expr) ($y:expr)) => {
+        $crate::vectors::IVec2::new_const(($x) as i32, ($y) as i32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::IVec2::new_const(($a) as i32, ($a) as i32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::ivec2!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
+        {
+            use $crate::vectors::IVec2Constructor;
+            $crate::vectors::IVec2::new($a)
+        }
+    };
+    (@exprs $($a:expr),+ $(,)?) => {
+        {
+            use $crate::vectors::IVec2Constructor;
+            $crate::vectors::IVec2::new(($($a,)+))
+        }
+    };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(ivec2 signed [] [$($args)+] $($args)+)
+    };
+}
+
+impl AsRef<IVec2> for IVec2 {
+    fn as_ref(&self) -> &IVec2 {
+        self
+    }
+}
\ No newline at end of file
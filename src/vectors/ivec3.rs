@@ -0,0 +1,107 @@
+use std::{fmt::Debug, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use nalgebra::Vector3;
+use vec_constructors::generate_constructors;
+
+use crate::{integer_arithmetic, private::Seal, slice_conversion, vector_index};
+
+use super::IVec2;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+pub struct IVec3(pub(in crate) Vector3<i32>);
+
+impl Debug for IVec3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl IVec3 {
+    pub const ZERO: IVec3 = IVec3::_new(0, 0, 0);
+    pub const ONE: IVec3 = IVec3::_new(1, 1, 1);
+
+    pub(in crate) const fn _new(x: i32, y: i32, z: i32) -> Self {
+        Self(Vector3::new(x, y, z))
+    }
+
+    /// Like `ivec3!`, but usable in const items.
+    pub const fn new_const(x: i32, y: i32, z: i32) -> Self {
+        Self::_new(x, y, z)
+    }
+
+    pub fn as_array(self) -> [i32; 3] {
+        self.0.data.0[0]
+    }
+
+    pub fn from_array(array: [i32; 3]) -> Self {
+        Self(Vector3::from(array))
+    }
+
+    pub fn as_slice(&self) -> &[i32; 3] {
+        &self.0.data.0[0]
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [i32; 3] {
+        &mut self.0.data.0[0]
+    }
+}
+
+integer_arithmetic!(IVec3, i32);
+vector_index!(IVec3, i32);
+slice_conversion!(IVec3, i32, 3);
+
+impl Seal for IVec3 {}
+
+#[diagnostic::on_unimplemented(
+    message = "`IVec3` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 3 components, and every argument must contribute at least one"
+)]
+pub trait IVec3Constructor<T>: Seal {
+    fn new(args: T) -> Self;
+}
+
+generate_constructors!(IVec3, IVec3Constructor, i32, 3, [Vec, IVec, UVec]);
+
+impl IVec3Constructor<IVec2> for IVec3 {
+    fn new(args: IVec2) -> IVec3 {
+        Self::_new(args.x(), args.y(), 0)
+    }
+}
+
+#[macro_export]
+macro_rules! ivec3 {
+    () => {
+        $crate::vectors::IVec3::ZERO
+    };
+    (@literals ($x:expr) ($y:expr) ($z:expr)) => {
+        $crate::vectors::IVec3::new_const(($x) as i32, ($y) as i32, ($z) as i32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::IVec3::new_const(($a) as i32, ($a) as i32, ($a) as i32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::ivec3!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
+        {
+            use $crate::vectors::IVec3Constructor;
+            $crate::vectors::IVec3::new($a)
+        }
+    };
+    (@exprs $($a:expr),+ $(,)?) => {
+        {
+            use $crate::vectors::IVec3Constructor;
+            $crate::vectors::IVec3::new(($($a,)+))
+        }
+    };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(ivec3 signed [] [$($args)+] $($args)+)
+    };
+}
+
+impl AsRef<IVec3> for IVec3 {
+    fn as_ref(&self) -> &IVec3 {
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::{fmt::Debug, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use nalgebra::Vector4;
+use vec_constructors::generate_constructors;
+
+use crate::{integer_arithmetic, private::Seal, slice_conversion, vector_index};
+
+use super::{IVec2, IVec3};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+pub struct IVec4(pub(in crate) Vector4<i32>);
+
+impl Debug for IVec4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl IVec4 {
+    pub const ZERO: IVec4 = IVec4::_new(0, 0, 0, 0);
+    pub const ONE: IVec4 = IVec4::_new(1, 1, 1, 1);
+
+    pub(in crate) const fn _new(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Self(Vector4::new(x, y, z, w))
+    }
+
+    /// Like `ivec4!`, but usable in const items.
+    pub const fn new_const(x: i32, y: i32, z: i32, w: i32) -> Self {
+        Self::_new(x, y, z, w)
+    }
+
+    pub fn as_array(self) -> [i32; 4] {
+        self.0.data.0[0]
+    }
+
+    pub fn from_array(array: [i32; 4]) -> Self {
+        Self(Vector4::from(array))
+    }
+
+    pub fn as_slice(&self) -> &[i32; 4] {
+        &self.0.data.0[0]
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [i32; 4] {
+        &mut self.0.data.0[0]
+    }
+}
+
+integer_arithmetic!(IVec4, i32);
+vector_index!(IVec4, i32);
+slice_conversion!(IVec4, i32, 4);
+
+impl Seal for IVec4 {}
+
+#[diagnostic::on_unimplemented(
+    message = "`IVec4` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 4 components, and every argument must contribute at least one"
+)]
+pub trait IVec4Constructor<T>: Seal {
+    fn new(args: T) -> Self;
+}
+
+generate_constructors!(IVec4, IVec4Constructor, i32, 4, [Vec, IVec, UVec]);
+
+impl IVec4Constructor<IVec2> for IVec4 {
+    fn new(args: IVec2) -> IVec4 {
+        Self::_new(args.x(), args.y(), 0, 0)
+    }
+}
+
+impl IVec4Constructor<IVec3> for IVec4 {
+    fn new(args: IVec3) -> IVec4 {
+        Self::_new(args.x(), args.y(), args.z(), 0)
+    }
+}
+
+#[macro_export]
+macro_rules! ivec4 {
+    () => {
+        $crate::vectors::IVec4::ZERO
+    };
+    (@literals ($x:expr) ($y:expr) ($z:expr) ($w:expr)) => {
+        $crate::vectors::IVec4::new_const(($x) as i32, ($y) as i32, ($z) as i32, ($w) as i32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::IVec4::new_const(($a) as i32, ($a) as i32, ($a) as i32, ($a) as i32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::ivec4!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
+        {
+            use $crate::vectors::IVec4Constructor;
+            $crate::vectors::IVec4::new($a)
+        }
+    };
+    (@exprs $($a:expr),+ $(,)?) => {
+        {
+            use $crate::vectors::IVec4Constructor;
+            $crate::vectors::IVec4::new(($($a,)+))
+        }
+    };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(ivec4 signed [] [$($args)+] $($args)+)
+    };
+}
+
+impl AsRef<IVec4> for IVec4 {
+    fn as_ref(&self) -> &IVec4 {
+        self
+    }
+}
\ No newline at end of file
@@ -1,13 +1,25 @@
 mod vec2;
 mod vec3;
 mod vec4;
+mod ivec2;
+mod ivec3;
+mod ivec4;
+mod uvec2;
+mod uvec3;
+mod uvec4;
 
 use nalgebra::{ArrayStorage, Const, Matrix};
 pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
+pub use ivec2::*;
+pub use ivec3::*;
+pub use ivec4::*;
+pub use uvec2::*;
+pub use uvec3::*;
+pub use uvec4::*;
 
-use crate::{inner_matrix::InnerMatrix, Make};
+use crate::{check_finite, inner_matrix::InnerMatrix, Make};
 
 pub trait VecN<const N: usize>: InnerMatrix<N, 1> + Make<Matrix<f32, Const<N>, Const<1>, ArrayStorage<f32, N, 1>>> + AsRef<Self> {
     fn as_array(self) -> [f32; N];
@@ -15,21 +27,13 @@ pub trait VecN<const N: usize>: InnerMatrix<N, 1> + Make<Matrix<f32, Const<N>, C
     fn as_slice(&self) -> &[f32; N];
     fn as_slice_mut(&mut self) -> &mut [f32; N];
     fn from_slice(slice: &[f32; N]) -> Self;
+    fn swizzle_dyn(&self, indices: &[usize]) -> Result<Swizzled, SwizzleError>;
+    fn swizzle_str(&self, pattern: &str) -> Result<Swizzled, SwizzleError>;
 }
 
-pub mod swizzles {
-    use swizz::generate_swizzles;
+pub mod swizzles;
 
-    use super::{Vec2, Vec3, Vec4};
-
-    generate_swizzles!(Vec2, xy, 4);
-    generate_swizzles!(Vec3, xyz, 4);
-    generate_swizzles!(Vec4, xyzw, 4);
-
-    generate_swizzles!(Vec2, rg, 4);
-    generate_swizzles!(Vec3, rgb, 4);
-    generate_swizzles!(Vec4, rgba, 4);
-}
+use swizzles::{SwizzleError, Swizzled};
 
 impl<const N: usize, T: InnerMatrix<N, 1> + Make<Matrix<f32, Const<N>, Const<1>, ArrayStorage<f32, N, 1>>> + AsRef<T>> VecN<N> for T {
     fn as_array(self) -> [f32; N] {
@@ -39,6 +43,8 @@ impl<const N: usize, T: InnerMatrix<N, 1> + Make<Matrix<f32, Const<N>, Const<1>,
     }
     
     fn from_array(array: [f32; N]) -> Self {
+        check_finite!("VecN::from_array", array);
+
         Self::make(Matrix::<f32, Const<N>, Const<1>, ArrayStorage<f32, N, 1>>::from_data(
             ArrayStorage::<f32, N, 1>([array])
         ))
@@ -53,9 +59,20 @@ impl<const N: usize, T: InnerMatrix<N, 1> + Make<Matrix<f32, Const<N>, Const<1>,
     }
     
     fn from_slice(slice: &[f32; N]) -> Self {
+        check_finite!("VecN::from_slice", slice);
+
         Self::make(Matrix::<f32, Const<N>, Const<1>, ArrayStorage<f32, N, 1>>::from_data(
             ArrayStorage::<f32, N, 1>([slice.to_owned()])
         ))
     }
 
+    fn swizzle_dyn(&self, indices: &[usize]) -> Result<Swizzled, SwizzleError> {
+        swizzles::swizzle_components(self.as_slice(), indices)
+    }
+
+    fn swizzle_str(&self, pattern: &str) -> Result<Swizzled, SwizzleError> {
+        let indices = swizzles::parse_pattern(pattern)?;
+
+        self.swizzle_dyn(&indices)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,227 @@
+//! Swizzle methods such as `v.xzy()` and `v.set_rg(...)` are generated for every vector type when the `swizzles`
+//! feature is enabled, which it is by default. Without it only the `x()`..`w()` component accessors are generated,
+//! and other patterns are available through [`Swizzle`].
+
+use std::fmt::{Debug, Display};
+
+use swizz::generate_swizzles;
+
+use super::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4};
+use crate::private::Seal;
+
+/// Picks `K` components by index, so that `v.swizzle([2, 1, 0])` is `v.zyx()`.
+///
+/// # Panics
+///
+/// Panics if an index is out of range. Use [`VecN::swizzle_dyn`](super::VecN::swizzle_dyn) when the indices aren't
+/// known to be valid.
+pub trait Swizzle<const K: usize> {
+    type Output;
+
+    fn swizzle(&self, indices: [usize; K]) -> Self::Output;
+}
+
+/// A component type and its vectors, for the results of runtime swizzles.
+pub trait SwizzleScalar: Seal + Copy + Debug + PartialEq {
+    type Vec2: Copy + Debug + PartialEq;
+    type Vec3: Copy + Debug + PartialEq;
+    type Vec4: Copy + Debug + PartialEq;
+}
+
+impl SwizzleScalar for f32 {
+    type Vec2 = Vec2;
+    type Vec3 = Vec3;
+    type Vec4 = Vec4;
+}
+
+impl SwizzleScalar for i32 {
+    type Vec2 = IVec2;
+    type Vec3 = IVec3;
+    type Vec4 = IVec4;
+}
+
+impl SwizzleScalar for u32 {
+    type Vec2 = UVec2;
+    type Vec3 = UVec3;
+    type Vec4 = UVec4;
+}
+
+/// The result of a runtime swizzle, whose length is only known once the pattern has been checked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Swizzled<S: SwizzleScalar = f32> {
+    Scalar(S),
+    Vec2(S::Vec2),
+    Vec3(S::Vec3),
+    Vec4(S::Vec4),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SwizzleError {
+    /// Patterns must select between 1 and 4 components.
+    InvalidLength(usize),
+    /// The character is not in any of the `xyzw`, `rgba` or `stpq` sets.
+    InvalidComponent(char),
+    /// Characters from different sets can't be combined, as in GLSL.
+    MixedSets,
+    /// The component index is past the end of the vector.
+    OutOfRange(usize),
+}
+
+impl Display for SwizzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwizzleError::InvalidLength(len) => write!(f, "swizzle selects {} components, expected 1 to 4", len),
+            SwizzleError::InvalidComponent(c) => write!(f, "'{}' is not a swizzle component", c),
+            SwizzleError::MixedSets => write!(f, "swizzle mixes components from different sets"),
+            SwizzleError::OutOfRange(i) => write!(f, "swizzle component {} is out of range", i),
+        }
+    }
+}
+
+impl std::error::Error for SwizzleError {}
+
+const SETS: [&str; 3] = ["xyzw", "rgba", "stpq"];
+
+pub(crate) fn parse_pattern(pattern: &str) -> Result<Vec<usize>, SwizzleError> {
+    let mut set = None;
+
+    pattern.chars().map(|c| {
+        let (s, i) = SETS.iter().enumerate()
+            .find_map(|(s, chars)| chars.find(c).map(|i| (s, i)))
+            .ok_or(SwizzleError::InvalidComponent(c))?;
+
+        if *set.get_or_insert(s) != s {
+            return Err(SwizzleError::MixedSets);
+        }
+
+        Ok(i)
+    }).collect()
+}
+
+fn select_components<S: Copy>(components: &[S], indices: &[usize]) -> Result<Vec<S>, SwizzleError> {
+    if indices.is_empty() || indices.len() > 4 {
+        return Err(SwizzleError::InvalidLength(indices.len()));
+    }
+
+    indices.iter().map(|i| components.get(*i).copied().ok_or(SwizzleError::OutOfRange(*i))).collect()
+}
+
+pub(crate) fn swizzle_components(components: &[f32], indices: &[usize]) -> Result<Swizzled, SwizzleError> {
+    Ok(match select_components(components, indices)?[..] {
+        [x] => Swizzled::Scalar(x),
+        [x, y] => Swizzled::Vec2(Vec2::_new(x, y)),
+        [x, y, z] => Swizzled::Vec3(Vec3::_new(x, y, z)),
+        [x, y, z, w] => Swizzled::Vec4(Vec4::_new(x, y, z, w)),
+        _ => unreachable!(),
+    })
+}
+
+macro_rules! swizzle_impl {
+    ($t:ident, $s:ty, $v2:ident, $v3:ident, $v4:ident) => {
+        impl Swizzle<1> for $t {
+            type Output = $s;
+
+            fn swizzle(&self, [a]: [usize; 1]) -> $s {
+                self.0[a]
+            }
+        }
+
+        impl Swizzle<2> for $t {
+            type Output = $v2;
+
+            fn swizzle(&self, [a, b]: [usize; 2]) -> $v2 {
+                $v2::_new(self.0[a], self.0[b])
+            }
+        }
+
+        impl Swizzle<3> for $t {
+            type Output = $v3;
+
+            fn swizzle(&self, [a, b, c]: [usize; 3]) -> $v3 {
+                $v3::_new(self.0[a], self.0[b], self.0[c])
+            }
+        }
+
+        impl Swizzle<4> for $t {
+            type Output = $v4;
+
+            fn swizzle(&self, [a, b, c, d]: [usize; 4]) -> $v4 {
+                $v4::_new(self.0[a], self.0[b], self.0[c], self.0[d])
+            }
+        }
+    };
+}
+
+swizzle_impl!(Vec2, f32, Vec2, Vec3, Vec4);
+swizzle_impl!(Vec3, f32, Vec2, Vec3, Vec4);
+swizzle_impl!(Vec4, f32, Vec2, Vec3, Vec4);
+swizzle_impl!(IVec2, i32, IVec2, IVec3, IVec4);
+swizzle_impl!(IVec3, i32, IVec2, IVec3, IVec4);
+swizzle_impl!(IVec4, i32, IVec2, IVec3, IVec4);
+swizzle_impl!(UVec2, u32, UVec2, UVec3, UVec4);
+swizzle_impl!(UVec3, u32, UVec2, UVec3, UVec4);
+swizzle_impl!(UVec4, u32, UVec2, UVec3, UVec4);
+
+// Integer vectors aren't `VecN`, so they get its runtime swizzles as inherent methods
+macro_rules! integer_runtime_swizzle {
+    ($t:ident, $s:ty, $v2:ident, $v3:ident, $v4:ident) => {
+        impl $t {
+            pub fn swizzle_dyn(&self, indices: &[usize]) -> Result<Swizzled<$s>, SwizzleError> {
+                Ok(match select_components(self.0.as_slice(), indices)?[..] {
+                    [x] => Swizzled::Scalar(x),
+                    [x, y] => Swizzled::Vec2($v2::_new(x, y)),
+                    [x, y, z] => Swizzled::Vec3($v3::_new(x, y, z)),
+                    [x, y, z, w] => Swizzled::Vec4($v4::_new(x, y, z, w)),
+                    _ => unreachable!(),
+                })
+            }
+
+            pub fn swizzle_str(&self, pattern: &str) -> Result<Swizzled<$s>, SwizzleError> {
+                let indices = parse_pattern(pattern)?;
+
+                self.swizzle_dyn(&indices)
+            }
+        }
+    };
+}
+
+integer_runtime_swizzle!(IVec2, i32, IVec2, IVec3, IVec4);
+integer_runtime_swizzle!(IVec3, i32, IVec2, IVec3, IVec4);
+integer_runtime_swizzle!(IVec4, i32, IVec2, IVec3, IVec4);
+integer_runtime_swizzle!(UVec2, u32, UVec2, UVec3, UVec4);
+integer_runtime_swizzle!(UVec3, u32, UVec2, UVec3, UVec4);
+integer_runtime_swizzle!(UVec4, u32, UVec2, UVec3, UVec4);
+
+#[cfg(feature = "swizzles")]
+mod methods {
+    use super::*;
+
+    generate_swizzles!(Vec2, [xy, rg, st], 4);
+    generate_swizzles!(Vec3, [xyz, rgb, stp], 4);
+    generate_swizzles!(Vec4, [xyzw, rgba, stpq], 4);
+
+    generate_swizzles!(IVec2, [xy, rg, st], 4, i32, IVec);
+    generate_swizzles!(IVec3, [xyz, rgb, stp], 4, i32, IVec);
+    generate_swizzles!(IVec4, [xyzw, rgba, stpq], 4, i32, IVec);
+
+    generate_swizzles!(UVec2, [xy, rg, st], 4, u32, UVec);
+    generate_swizzles!(UVec3, [xyz, rgb, stp], 4, u32, UVec);
+    generate_swizzles!(UVec4, [xyzw, rgba, stpq], 4, u32, UVec);
+}
+
+#[cfg(not(feature = "swizzles"))]
+mod methods {
+    use super::*;
+
+    generate_swizzles!(Vec2, xy, 1);
+    generate_swizzles!(Vec3, xyz, 1);
+    generate_swizzles!(Vec4, xyzw, 1);
+
+    generate_swizzles!(IVec2, xy, 1, i32, IVec);
+    generate_swizzles!(IVec3, xyz, 1, i32, IVec);
+    generate_swizzles!(IVec4, xyzw, 1, i32, IVec);
+
+    generate_swizzles!(UVec2, xy, 1, u32, UVec);
+    generate_swizzles!(UVec3, xyz, 1, u32, UVec);
+    generate_swizzles!(UVec4, xyzw, 1, u32, UVec);
+}
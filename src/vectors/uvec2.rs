@@ -0,0 +1,99 @@
+use std::{fmt::Debug, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use nalgebra::Vector2;
+use vec_constructors::generate_constructors;
+
+use crate::{integer_arithmetic, private::Seal, slice_conversion, vector_index};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+pub struct UVec2(pub(in crate) Vector2<u32>);
+
+impl Debug for UVec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl UVec2 {
+    pub const ZERO: UVec2 = UVec2::_new(0, 0);
+    pub const ONE: UVec2 = UVec2::_new(1, 1);
+
+    pub(in crate) const fn _new(x: u32, y: u32) -> Self {
+        Self(Vector2::new(x, y))
+    }
+
+    /// Like `uvec2!`, but usable in const items.
+    pub const fn new_const(x: u32, y: u32) -> Self {
+        Self::_new(x, y)
+    }
+
+    pub fn as_array(self) -> [u32; 2] {
+        self.0.data.0[0]
+    }
+
+    pub fn from_array(array: [u32; 2]) -> Self {
+        Self(Vector2::from(array))
+    }
+
+    pub fn as_slice(&self) -> &[u32; 2] {
+        &self.0.data.0[0]
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [u32; 2] {
+        &mut self.0.data.0[0]
+    }
+}
+
+integer_arithmetic!(UVec2, u32);
+vector_index!(UVec2, u32);
+slice_conversion!(UVec2, u32, 2);
+
+impl Seal for UVec2 {}
+
+#[diagnostic::on_unimplemented(
+    message = "`UVec2` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 2 components, and every argument must contribute at least one"
+)]
+pub trait UVec2Constructor<T>: Seal {
+    fn new(args: T) -> Self;
+}
+
+generate_constructors!(UVec2, UVec2Constructor, u32, 2, [Vec, IVec, UVec]);
+
+#[macro_export]
+macro_rules! uvec2 {
+    () => {
+        $crate::vectors::UVec2::ZERO
+    };
+    (@literals ($x:expr) ($y:expr)) => {
+        $crate::vectors::UVec2::new_const(($x) as u32, ($y) as u32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::UVec2::new_const(($a) as u32, ($a) as u32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::uvec2!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
+        {
+            use $crate::vectors::UVec2Constructor;
+            $crate::vectors::UVec2::new($a)
+        }
+    };
+    (@exprs $($a:expr),+ $(,)?) => {
+        {
+            use $crate::vectors::UVec2Constructor;
+            $crate::vectors::UVec2::new(($($a,)+))
+        }
+    };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(uvec2 unsigned [] [$($args)+] $($args)+)
+    };
+}
+
+impl AsRef<UVec2> for UVec2 {
+    fn as_ref(&self) -> &UVec2 {
+        self
+    }
+}
\ No newline at end of file
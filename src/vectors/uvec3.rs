@@ -0,0 +1,107 @@
+use std::{fmt::Debug, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use nalgebra::Vector3;
+use vec_constructors::generate_constructors;
+
+use crate::{integer_arithmetic, private::Seal, slice_conversion, vector_index};
+
+use super::UVec2;
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+pub struct UVec3(pub(in crate) Vector3<u32>);
+
+impl Debug for UVec3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl UVec3 {
+    pub const ZERO: UVec3 = UVec3::_new(0, 0, 0);
+    pub const ONE: UVec3 = UVec3::_new(1, 1, 1);
+
+    pub(in crate) const fn _new(x: u32, y: u32, z: u32) -> Self {
+        Self(Vector3::new(x, y, z))
+    }
+
+    /// Like `uvec3!`, but usable in const items.
+    pub const fn new_const(x: u32, y: u32, z: u32) -> Self {
+        Self::_new(x, y, z)
+    }
+
+    pub fn as_array(self) -> [u32; 3] {
+        self.0.data.0[0]
+    }
+
+    pub fn from_array(array: [u32; 3]) -> Self {
+        Self(Vector3::from(array))
+    }
+
+    pub fn as_slice(&self) -> &[u32; 3] {
+        &self.0.data.0[0]
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [u32; 3] {
+        &mut self.0.data.0[0]
+    }
+}
+
+integer_arithmetic!(UVec3, u32);
+vector_index!(UVec3, u32);
+slice_conversion!(UVec3, u32, 3);
+
+impl Seal for UVec3 {}
+
+#[diagnostic::on_unimplemented(
+    message = "`UVec3` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 3 components, and every argument must contribute at least one"
+)]
+pub trait UVec3Constructor<T>: Seal {
+    fn new(args: T) -> Self;
+}
+
+generate_constructors!(UVec3, UVec3Constructor, u32, 3, [Vec, IVec, UVec]);
+
+impl UVec3Constructor<UVec2> for UVec3 {
+    fn new(args: UVec2) -> UVec3 {
+        Self::_new(args.x(), args.y(), 0)
+    }
+}
+
+#[macro_export]
+macro_rules! uvec3 {
+    () => {
+        $crate::vectors::UVec3::ZERO
+    };
+    (@literals ($x:expr) ($y:expr) ($z:expr)) => {
+        $crate::vectors::UVec3::new_const(($x) as u32, ($y) as u32, ($z) as u32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::UVec3::new_const(($a) as u32, ($a) as u32, ($a) as u32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::uvec3!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
+        {
+            use $crate::vectors::UVec3Constructor;
+            $crate::vectors::UVec3::new($a)
+        }
+    };
+    (@exprs $($a:expr),+ $(,)?) => {
+        {
+            use $crate::vectors::UVec3Constructor;
+            $crate::vectors::UVec3::new(($($a,)+))
+        }
+    };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(uvec3 unsigned [] [$($args)+] $($args)+)
+    };
+}
+
+impl AsRef<UVec3> for UVec3 {
+    fn as_ref(&self) -> &UVec3 {
+        self
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use std::{fmt::Debug, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
+
+use nalgebra::Vector4;
+use vec_constructors::generate_constructors;
+
+use crate::{integer_arithmetic, private::Seal, slice_conversion, vector_index};
+
+use super::{UVec2, UVec3};
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+pub struct UVec4(pub(in crate) Vector4<u32>);
+
+impl Debug for UVec4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl UVec4 {
+    pub const ZERO: UVec4 = UVec4::_new(0, 0, 0, 0);
+    pub const ONE: UVec4 = UVec4::_new(1, 1, 1, 1);
+
+    pub(in crate) const fn _new(x: u32, y: u32, z: u32, w: u32) -> Self {
+        Self(Vector4::new(x, y, z, w))
+    }
+
+    /// Like `uvec4!`, but usable in const items.
+    pub const fn new_const(x: u32, y: u32, z: u32, w: u32) -> Self {
+        Self::_new(x, y, z, w)
+    }
+
+    pub fn as_array(self) -> [u32; 4] {
+        self.0.data.0[0]
+    }
+
+    pub fn from_array(array: [u32; 4]) -> Self {
+        Self(Vector4::from(array))
+    }
+
+    pub fn as_slice(&self) -> &[u32; 4] {
+        &self.0.data.0[0]
+    }
+
+    pub fn as_slice_mut(&mut self) -> &mut [u32; 4] {
+        &mut self.0.data.0[0]
+    }
+}
+
+integer_arithmetic!(UVec4, u32);
+vector_index!(UVec4, u32);
+slice_conversion!(UVec4, u32, 4);
+
+impl Seal for UVec4 {}
+
+#[diagnostic::on_unimplemented(
+    message = "`UVec4` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 4 components, and every argument must contribute at least one"
+)]
+pub trait UVec4Constructor<T>: Seal {
+    fn new(args: T) -> Self;
+}
+
+generate_constructors!(UVec4, UVec4Constructor, u32, 4, [Vec, IVec, UVec]);
+
+impl UVec4Constructor<UVec2> for UVec4 {
+    fn new(args: UVec2) -> UVec4 {
+        Self::_new(args.x(), args.y(), 0, 0)
+    }
+}
+
+impl UVec4Constructor<UVec3> for UVec4 {
+    fn new(args: UVec3) -> UVec4 {
+        Self::_new(args.x(), args.y(), args.z(), 0)
+    }
+}
+
+#[macro_export]
+macro_rules! uvec4 {
+    () => {
+        $crate::vectors::UVec4::ZERO
+    };
+    (@literals ($x:expr) ($y:expr) ($z:expr) ($w:expr)) => {
+        $crate::vectors::UVec4::new_const(($x) as u32, ($y) as u32, ($z) as u32, ($w) as u32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::UVec4::new_const(($a) as u32, ($a) as u32, ($a) as u32, ($a) as u32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::uvec4!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
+        {
+            use $crate::vectors::UVec4Constructor;
+            $crate::vectors::UVec4::new($a)
+        }
+    };
+    (@exprs $($a:expr),+ $(,)?) => {
+        {
+            use $crate::vectors::UVec4Constructor;
+            $crate::vectors::UVec4::new(($($a,)+))
+        }
+    };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(uvec4 unsigned [] [$($args)+] $($args)+)
+    };
+}
+
+impl AsRef<UVec4> for UVec4 {
+    fn as_ref(&self) -> &UVec4 {
+        self
+    }
+}
\ No newline at end of file
@@ -1,11 +1,10 @@
-use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign}};
+use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 
 use multi_impl::multi_impl;
 use nalgebra::{Matrix, Vector2};
+use vec_constructors::generate_constructors;
 
-use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, private::Seal, GLScalar, Make};
-
-use super::{Vec3, Vec4};
+use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, check_finite_const, private::Seal, slice_conversion, vector_index, GLScalar, Make};
 
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
@@ -24,61 +23,60 @@ impl Vec2 {
     pub(in crate) const fn _new(x: f32, y: f32) -> Self {
         Self(Vector2::new(x, y))
     }
+
+    /// Like `vec2!`, but usable in const items.
+    pub const fn new_const(x: f32, y: f32) -> Self {
+        check_finite_const!("Vec2::new_const", x, y);
+
+        Self::_new(x, y)
+    }
 }
 
 matrix_arithmetic!(Vec2);
+vector_index!(Vec2, f32);
+slice_conversion!(Vec2, f32, 2);
 
 impl Seal for Vec2 {}
 
+#[diagnostic::on_unimplemented(
+    message = "`Vec2` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 2 components, and every argument must contribute at least one"
+)]
 pub trait Vec2Constructor<T>: Seal {
     fn new(args: T) -> Self;
 }
 
-impl<A: GLScalar, B: GLScalar> Vec2Constructor<(A, B)> for Vec2 {
-    fn new(args: (A, B)) -> Self {
-        let (a, b) = args;
-        Self::_new(a.as_(), b.as_())
-    }
-}
-
-impl<A: GLScalar> Vec2Constructor<A> for Vec2 {
-    fn new(args: A) -> Self {
-        Self::_new(args.as_(), args.as_())
-    }
-}
-
-impl Vec2Constructor<Vec3> for Vec2 {
-    fn new(args: Vec3) -> Self {
-        Self::_new(args.x(), args.y())
-    }
-}
-
-impl Vec2Constructor<Vec4> for Vec2 {
-    fn new(args: Vec4) -> Self {
-        Self::_new(args.x(), args.y())
-    }
-}
+generate_constructors!(Vec2, Vec2Constructor, f32, 2, [Vec, IVec, UVec]);
 
 #[macro_export]
 macro_rules! vec2 {
-    ($a:expr, $b:expr) => {
-        {
-            use $crate::vectors::Vec2Constructor;
-            $crate::vectors::Vec2::new(($a, $b))
-        }
+    () => {
+        $crate::vectors::Vec2::ZERO
+    };
+    (@literals ($x:expr) ($y:expr)) => {
+        $crate::vectors::Vec2::new_const(($x) as f32, ($y) as f32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::Vec2::new_const(($a) as f32, ($a) as f32)
     };
-    ($a:expr) => {
+    (@literals $($a:tt)*) => {
+        $crate::vec2!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
         {
             use $crate::vectors::Vec2Constructor;
             $crate::vectors::Vec2::new($a)
         }
     };
-    () => {
+    (@exprs $($a:expr),+ $(,)?) => {
         {
             use $crate::vectors::Vec2Constructor;
-            $crate::vectors::Vec2::new(0)
+            $crate::vectors::Vec2::new(($($a,)+))
         }
     };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(vec2 signed [] [$($args)+] $($args)+)
+    };
 }
 
 impl InnerMatrix<2, 1> for Vec2 {
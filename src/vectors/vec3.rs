@@ -1,11 +1,12 @@
-use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign}};
+use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 
 use multi_impl::multi_impl;
 use nalgebra::{Matrix, Vector3};
+use vec_constructors::generate_constructors;
 
-use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, private::Seal, GLScalar, Make};
+use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, check_finite_const, private::Seal, slice_conversion, vector_index, GLScalar, Make};
 
-use super::{Vec2, Vec4};
+use super::Vec2;
 
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
@@ -24,42 +25,30 @@ impl Vec3 {
     pub(in crate) const fn _new(x: f32, y: f32, z: f32) -> Vec3 {
         Self(Vector3::new(x, y, z))
     }
+
+    /// Like `vec3!`, but usable in const items.
+    pub const fn new_const(x: f32, y: f32, z: f32) -> Self {
+        check_finite_const!("Vec3::new_const", x, y, z);
+
+        Self::_new(x, y, z)
+    }
 }
 
 matrix_arithmetic!(Vec3);
+vector_index!(Vec3, f32);
+slice_conversion!(Vec3, f32, 3);
 
 impl Seal for Vec3 {}
 
+#[diagnostic::on_unimplemented(
+    message = "`Vec3` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 3 components, and every argument must contribute at least one"
+)]
 pub trait Vec3Constructor<T>: Seal {
-    fn new(args: T) -> Vec3;
-}
-
-impl<A: GLScalar, B: GLScalar, C: GLScalar> Vec3Constructor<(A, B, C)> for Vec3 {
-    fn new(args: (A, B, C)) -> Vec3 {
-        let (a, b, c) = args;
-        Self::_new(a.as_(), b.as_(), c.as_())
-    }
-}
-
-impl<B: GLScalar> Vec3Constructor<(Vec2, B)> for Vec3 {
-    fn new(args: (Vec2, B)) -> Vec3 {
-        let (a, b) = args;
-        Self::_new(a.x(), a.y(), b.as_())
-    }
+    fn new(args: T) -> Self;
 }
 
-impl<A: GLScalar> Vec3Constructor<(A, Vec2)> for Vec3 {
-    fn new(args: (A, Vec2)) -> Vec3 {
-        let (a, b) = args;
-        Self::_new(a.as_(), b.x(), b.y())
-    }
-}
-
-impl<A: GLScalar> Vec3Constructor<A> for Vec3 {
-    fn new(args: A) -> Vec3 {
-        Self::_new(args.as_(), args.as_(), args.as_())
-    }
-}
+generate_constructors!(Vec3, Vec3Constructor, f32, 3, [Vec, IVec, UVec]);
 
 impl Vec3Constructor<Vec2> for Vec3 {
     fn new(args: Vec2) -> Vec3 {
@@ -67,38 +56,35 @@ impl Vec3Constructor<Vec2> for Vec3 {
     }
 }
 
-impl Vec3Constructor<Vec4> for Vec3 {
-    fn new(args: Vec4) -> Vec3 {
-        Self::_new(args.x(), args.y(), args.z())
-    }
-}
-
 #[macro_export]
 macro_rules! vec3 {
-    ($a:expr, $b:expr, $c:expr) => {
-        {
-            use $crate::vectors::Vec3Constructor;
-            $crate::vectors::Vec3::new(($a, $b, $c))
-        }
+    () => {
+        $crate::vectors::Vec3::ZERO
     };
-    ($a:expr, $b:expr) => {
-        {
-            use $crate::vectors::Vec3Constructor;
-            $crate::vectors::Vec3::new(($a, $b))
-        }
+    (@literals ($x:expr) ($y:expr) ($z:expr)) => {
+        $crate::vectors::Vec3::new_const(($x) as f32, ($y) as f32, ($z) as f32)
+    };
+    (@literals ($a:expr)) => {
+        $crate::vectors::Vec3::new_const(($a) as f32, ($a) as f32, ($a) as f32)
     };
-    ($a:expr) => {
+    (@literals $($a:tt)*) => {
+        $crate::vec3!(@exprs $($a),*)
+    };
+    (@exprs $a:expr $(,)?) => {
         {
             use $crate::vectors::Vec3Constructor;
             $crate::vectors::Vec3::new($a)
         }
     };
-    () => {
+    (@exprs $($a:expr),+ $(,)?) => {
         {
             use $crate::vectors::Vec3Constructor;
-            $crate::vectors::Vec3::new(0)
+            $crate::vectors::Vec3::new(($($a,)+))
         }
     };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(vec3 signed [] [$($args)+] $($args)+)
+    };
 }
 
 impl InnerMatrix<3, 1> for Vec3 {
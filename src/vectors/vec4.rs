@@ -1,9 +1,10 @@
-use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign}};
+use std::{fmt::Debug, ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}};
 
 use multi_impl::multi_impl;
 use nalgebra::Vector4;
+use vec_constructors::generate_constructors;
 
-use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, private::Seal, GLScalar, Make};
+use crate::{inner_matrix::InnerMatrix, matrix_arithmetic, check_finite_const, private::Seal, slice_conversion, vector_index, GLScalar, Make};
 
 use super::{Vec2, Vec3};
 
@@ -24,70 +25,42 @@ impl Vec4 {
     pub(in crate) const fn _new(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self(Vector4::new(x, y, z, w))
     }
-}
-
-matrix_arithmetic!(Vec4);
-
-impl Seal for Vec4 {}
-
-pub trait Vec4Constructor<T>: Seal {
-    fn new(args: T) -> Vec4;
-}
-
-impl<A: GLScalar, B: GLScalar, C: GLScalar, D: GLScalar> Vec4Constructor<(A, B, C, D)> for Vec4 {
-    fn new(args: (A, B, C, D)) -> Vec4 {
-        let (a, b, c, d) = args;
-        Vec4::_new(a.as_(), b.as_(), c.as_(), d.as_())
-    }
-}
 
-impl<B: GLScalar, C: GLScalar> Vec4Constructor<(Vec2, B, C)> for Vec4 {
-    fn new(args: (Vec2, B, C)) -> Vec4 {
-        let (a, b, c) = args;
-        Self::_new(a.x(), a.y(), b.as_(), c.as_())
-    }
-}
-
-impl<A: GLScalar, C: GLScalar> Vec4Constructor<(A, Vec2, C)> for Vec4 {
-    fn new(args: (A, Vec2, C)) -> Vec4 {
-        let (a, b, c) = args;
-        Self::_new(a.as_(), b.x(), b.y(), c.as_())
-    }
-}
+    /// Like `vec4!`, but usable in const items.
+    pub const fn new_const(x: f32, y: f32, z: f32, w: f32) -> Self {
+        check_finite_const!("Vec4::new_const", x, y, z, w);
 
-impl<A: GLScalar, B: GLScalar> Vec4Constructor<(A, B, Vec2)> for Vec4 {
-    fn new(args: (A, B, Vec2)) -> Vec4 {
-        let (a, b, c) = args;
-        Self::_new(a.as_(), b.as_(), c.x(), c.y())
+        Self::_new(x, y, z, w)
     }
 }
 
-impl<B: GLScalar> Vec4Constructor<(Vec3, B)> for Vec4 {
-    fn new(args: (Vec3, B)) -> Vec4 {
-        let (a, b) = args;
-        Self::_new(a.x(), a.y(), a.z(), b.as_())
-    }
-}
+matrix_arithmetic!(Vec4);
+vector_index!(Vec4, f32);
+slice_conversion!(Vec4, f32, 4);
 
-impl<A: GLScalar> Vec4Constructor<(A, Vec3)> for Vec4 {
-    fn new(args: (A, Vec3)) -> Vec4 {
-        let (a, b) = args;
-        Self::_new(a.as_(), b.x(), b.y(), b.z())
-    }
-}
+impl Seal for Vec4 {}
 
-impl Vec4Constructor<(Vec2, Vec2)> for Vec4 {
-    fn new(args: (Vec2, Vec2)) -> Vec4 {
-        let (a, b) = args;
-        Self::_new(a.x(), a.y(), b.x(), b.y())
-    }
+/// Implemented for every GLSL-style argument list: any mix of scalars and vectors that supplies at least four
+/// components, where every argument contributes at least one. The last argument may be only partially used.
+///
+/// ```compile_fail
+/// // Three components can't fill a Vec4
+/// let v = gl_types::vec4!(1, 2, 3);
+/// ```
+///
+/// ```compile_fail
+/// // The last scalar would not be used
+/// let v = gl_types::vec4!(gl_types::vec4!(1), 2);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`Vec4` can't be constructed from `{T}`",
+    note = "the arguments must supply at least 4 components, and every argument must contribute at least one"
+)]
+pub trait Vec4Constructor<T>: Seal {
+    fn new(args: T) -> Self;
 }
 
-impl<A: GLScalar> Vec4Constructor<A> for Vec4 {
-    fn new(args: A) -> Vec4 {
-        Self::_new( args.as_(), args.as_(), args.as_(), args.as_())
-    }
-}
+generate_constructors!(Vec4, Vec4Constructor, f32, 4, [Vec, IVec, UVec]);
 
 impl Vec4Constructor<Vec2> for Vec4 {
     fn new(args: Vec2) -> Vec4 {
@@ -103,36 +76,33 @@ impl Vec4Constructor<Vec3> for Vec4 {
 
 #[macro_export]
 macro_rules! vec4 {
-    ($a:expr, $b:expr, $c:expr, $d:expr) => {
-        {
-            use $crate::vectors::Vec4Constructor;
-            $crate::vectors::Vec4::new(($a, $b, $c, $d))
-        }
+    () => {
+        $crate::vectors::Vec4::ZERO
     };
-    ($a:expr, $b:expr, $c:expr) => {
-        {
-            use $crate::vectors::Vec4Constructor;
-            $crate::vectors::Vec4::new(($a, $b, $c))
-        }
+    (@literals ($x:expr) ($y:expr) ($z:expr) ($w:expr)) => {
+        $crate::vectors::Vec4::new_const(($x) as f32, ($y) as f32, ($z) as f32, ($w) as f32)
     };
-    ($a:expr, $b:expr) => {
-        {
-            use $crate::vectors::Vec4Constructor;
-            $crate::vectors::Vec4::new(($a, $b))
-        }
+    (@literals ($a:expr)) => {
+        $crate::vectors::Vec4::new_const(($a) as f32, ($a) as f32, ($a) as f32, ($a) as f32)
+    };
+    (@literals $($a:tt)*) => {
+        $crate::vec4!(@exprs $($a),*)
     };
-    ($a:expr) => {
+    (@exprs $a:expr $(,)?) => {
         {
             use $crate::vectors::Vec4Constructor;
             $crate::vectors::Vec4::new($a)
         }
     };
-    () => {
+    (@exprs $($a:expr),+ $(,)?) => {
         {
             use $crate::vectors::Vec4Constructor;
-            $crate::vectors::Vec4::new(0)
+            $crate::vectors::Vec4::new(($($a,)+))
         }
     };
+    ($($args:tt)+) => {
+        $crate::__literal_args!(vec4 signed [] [$($args)+] $($args)+)
+    };
 }
 
 impl InnerMatrix<4, 1> for Vec4 {
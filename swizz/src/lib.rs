@@ -2,23 +2,105 @@ use std::collections::HashSet;
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse::Parse, parse_macro_input, token::Comma, Ident, LitInt};
+use syn::{bracketed, parse::Parse, parse_macro_input, parse_quote, punctuated::Punctuated, token::{Bracket, Comma}, Error, Ident, LitInt, Type};
 
 
+/// `generate_swizzles!(Vec3, xyz, 4)`, or `generate_swizzles!(Vec3, [xyz, rgb, stp], 4)` for several
+/// sets at once. Sets for the same type should share an invocation so that collisions between them are caught.
+///
+/// The scalar type and vector family default to `f32` and `Vec`, so single components are returned as `f32` and
+/// longer patterns as `Vec2`..`Vec4`. Other families name them explicitly: `generate_swizzles!(IVec3, xyz, 4, i32, IVec)`.
 struct Input {
     _type: Ident,
-    ident: Ident,
-    len: LitInt
+    sets: Vec<Ident>,
+    len: LitInt,
+    scalar: Type,
+    prefix: Ident
 }
 
 impl Parse for Input {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        Ok(Input {
-            _type: input.parse()?,
-            ident: {input.parse::<Comma>()?; input.parse()?},
-            len: {input.parse::<Comma>()?; input.parse()?}
-        })
+        let _type = input.parse()?;
+        input.parse::<Comma>()?;
+
+        let sets = if input.peek(Bracket) {
+            let content;
+            bracketed!(content in input);
+            Punctuated::<Ident, Comma>::parse_terminated(&content)?.into_iter().collect()
+        } else {
+            vec![input.parse()?]
+        };
+
+        input.parse::<Comma>()?;
+        let len: LitInt = input.parse()?;
+
+        validate_sets(&sets, len.base10_parse()?)?;
+
+        let (scalar, prefix) = if input.is_empty() {
+            (parse_quote!(f32), Ident::new("Vec", proc_macro2::Span::call_site()))
+        } else {
+            input.parse::<Comma>()?;
+            let scalar = input.parse()?;
+            input.parse::<Comma>()?;
+            (scalar, input.parse()?)
+        };
+
+        Ok(Input { _type, sets, len, scalar, prefix })
+    }
+}
+
+/// Methods the vector types already have, inherently or through `VecN`, `Swizzle`, their constructor traits and the
+/// std operator traits. A swizzle with one of these names would clash with it or silently shadow it.
+const RESERVED: &[&str] = &[
+    "new", "new_const", "from_array", "as_array", "as_slice", "as_slice_mut", "from_slice", "swizzle", "swizzle_dyn",
+    "swizzle_str", "as_nalgebra", "as_nalgebra_mut", "get_inner_matrix", "get_inner_matrix_mut", "into_inner_matrix",
+    "make", "operate", "approx_eq", "approx_eq_with", "column", "row", "set_column", "set_row", "as_ref", "clone",
+    "eq", "ne", "lt", "le", "gt", "ge", "fmt", "hash", "index", "index_mut", "add", "sub", "mul", "div", "rem", "neg",
+    "from", "into", "try_from", "from_str",
+];
+
+fn validate_sets(sets: &[Ident], len: usize) -> syn::Result<()> {
+    let mut seen: Vec<(char, &Ident)> = Vec::new();
+
+    if sets.is_empty() {
+        return Err(Error::new(proc_macro2::Span::call_site(), "expected at least one swizzle set"));
+    }
+
+    for set in sets {
+        let chars: Vec<char> = set.to_string().chars().collect();
+
+        if let Some(c) = chars.iter().find(|c| !c.is_ascii_lowercase()) {
+            return Err(Error::new(set.span(), format!("swizzle set `{}` contains `{}`; only lowercase ASCII letters are allowed", set, c)));
+        }
+
+        if !is_unique(&set.to_string()) {
+            return Err(Error::new(set.span(), format!("swizzle set `{}` contains duplicate characters", set)));
+        }
+
+        if chars.len() != sets[0].to_string().len() {
+            return Err(Error::new(set.span(), format!("swizzle set `{}` has {} components but `{}` has {}", set, chars.len(), sets[0], sets[0].to_string().len())));
+        }
+
+        for c in &chars {
+            if let Some((_, other)) = seen.iter().find(|(s, _)| s == c) {
+                return Err(Error::new(set.span(), format!("swizzle sets `{}` and `{}` both use `{}`, so their methods would collide", other, set, c)));
+            }
+        }
+        seen.extend(chars.iter().map(|c| (*c, set)));
+
+        for n in 1..len + 1 {
+            let names = combos(n, &chars);
+            if let Some(name) = names.iter().find(|name| syn::parse_str::<Ident>(name).is_err()) {
+                return Err(Error::new(set.span(), format!("swizzle set `{}` would generate `{}`, which is a Rust keyword", set, name)));
+            }
+
+            if let Some(name) = names.iter().flat_map(|name| generated_methods(name)).find(|name| RESERVED.contains(&name.as_str())) {
+                return Err(Error::new(set.span(), format!("swizzle set `{}` would generate `{}`, which clashes with an existing method", set, name)));
+            }
+        }
     }
+
+    Ok(())
 }
 
 fn combos(n: usize, chars: &[char]) -> HashSet<String> {
@@ -34,40 +116,94 @@ fn combos(n: usize, chars: &[char]) -> HashSet<String> {
     }).collect()
 } 
 
+/// The getter, setter and (for single components) mutable accessor generated for a swizzle pattern.
+fn generated_methods(name: &str) -> Vec<String> {
+    let mut methods = vec![name.to_owned()];
+    if name.len() == 1 {
+        methods.push(format!("{}_mut", name));
+    }
+    if is_unique(name) {
+        methods.push(format!("set_{}", name));
+    }
+
+    methods
+}
+
+fn is_unique(name: &str) -> bool {
+    let set: HashSet<char> = name.chars().collect();
+    set.len() == name.len()
+}
+
+/// ```compile_fail
+/// // The `enw` set would generate a `new` swizzle, which clashes with the constructor
+/// struct Vec2([f32; 2]);
+/// struct Vec3([f32; 3]);
+/// impl Vec2 { pub fn _new(x: f32, y: f32) -> Self { Self([x, y]) } }
+/// impl Vec3 { pub fn _new(x: f32, y: f32, z: f32) -> Self { Self([x, y, z]) } }
+/// swizz::generate_swizzles!(Vec3, enw, 3);
+/// ```
 #[proc_macro]
 pub fn generate_swizzles(item: TokenStream) -> TokenStream {
-    let Input { _type, ident, len} = parse_macro_input!(item as Input);
-    let chars: Vec<char> = ident.to_string().chars().collect();
+    let Input { _type, sets, len, scalar, prefix } = parse_macro_input!(item as Input);
     let mut fns = Vec::new();
-    for n in 1..len.base10_parse::<usize>().unwrap() + 1 {
-        let names = combos(n, &chars);
-        let vecn: proc_macro2::TokenStream = format!("Vec{}", n).parse().unwrap();
-
-        names.into_iter().for_each(|name| {
-            let mut constructor = String::new();
-            name.chars().for_each(|char| {
-                let i = chars.iter().position(|c| *c == char).unwrap();
-                constructor += &format!("self.0[{}], ", i);
-            });
-            constructor.truncate(constructor.len() - 2);
-            let constructor: proc_macro2::TokenStream = constructor.parse().unwrap();
-            let name: proc_macro2::TokenStream = name.parse().unwrap();
-            if n > 1 {
-                fns.push(
-                    quote! {
-                        pub fn #name(&self) -> #vecn {
-                            #vecn::_new(#constructor)
-                        }
-                });
-            } else {
-                fns.push(
-                    quote! {
-                        pub fn #name(&self) -> f32 {
-                            #constructor
-                        }
+    let len = len.base10_parse::<usize>().unwrap();
+    for set in &sets {
+        let chars: Vec<char> = set.to_string().chars().collect();
+        for n in 1..len + 1 {
+            let names = combos(n, &chars);
+            let vecn = Ident::new(&format!("{}{}", prefix, n), prefix.span());
+
+            names.into_iter().for_each(|name| {
+                let indices: Vec<usize> = name.chars().map(|char| chars.iter().position(|c| *c == char).unwrap()).collect();
+                let mut constructor = String::new();
+                indices.iter().for_each(|i| {
+                    constructor += &format!("self.0[{}], ", i);
                 });
-            }
-        });
+                constructor.truncate(constructor.len() - 2);
+                let constructor: proc_macro2::TokenStream = constructor.parse().unwrap();
+                let setter: proc_macro2::TokenStream = format!("set_{}", name).parse().unwrap();
+                let unique = is_unique(&name);
+                let name_ident: proc_macro2::TokenStream = name.parse().unwrap();
+                if n > 1 {
+                    fns.push(
+                        quote! {
+                            pub fn #name_ident(&self) -> #vecn {
+                                #vecn::_new(#constructor)
+                            }
+                    });
+
+                    // Only patterns without repeated components can be written to, as in GLSL
+                    if unique {
+                        let assignments: proc_macro2::TokenStream = indices.iter().enumerate().map(|(j, i)| {
+                            format!("self.0[{}] = value.0[{}];", i, j)
+                        }).collect::<String>().parse().unwrap();
+
+                        fns.push(
+                            quote! {
+                                pub fn #setter(&mut self, value: #vecn) {
+                                    #assignments
+                                }
+                        });
+                    }
+                } else {
+                    let getter_mut: proc_macro2::TokenStream = format!("{}_mut", name).parse().unwrap();
+                    fns.push(
+                        quote! {
+                            pub fn #name_ident(&self) -> #scalar {
+                                #constructor
+                            }
+
+                            pub fn #setter(&mut self, value: #scalar) {
+                                #constructor = value;
+                            }
+
+                            pub fn #getter_mut(&mut self) -> &mut #scalar {
+                                &mut #constructor
+                            }
+                    });
+                }
+            });
+        }
     }
     let body: proc_macro2::TokenStream = fns.into_iter().collect();
 
@@ -141,4 +277,23 @@ fn combo() {
     let test = combos(3, &t[..]);
 
     assert_eq!(expected, test);
-}
\ No newline at end of file
+}
+
+#[test]
+fn validation() {
+    let idents = |sets: &[&str]| sets.iter().map(|s| Ident::new(s, proc_macro2::Span::call_site())).collect::<Vec<_>>();
+
+    assert!(validate_sets(&idents(&["xyzw", "rgba", "stpq"]), 4).is_ok());
+    assert!(validate_sets(&idents(&["xy"]), 4).is_ok());
+
+    assert!(validate_sets(&idents(&[]), 4).is_err());
+    assert!(validate_sets(&idents(&["xyzx"]), 4).is_err());
+    assert!(validate_sets(&idents(&["xy_z"]), 4).is_err());
+    assert!(validate_sets(&idents(&["xyzw", "rgb"]), 4).is_err());
+    assert!(validate_sets(&idents(&["xyzw", "rgbw"]), 4).is_err());
+    assert!(validate_sets(&idents(&["fnq"]), 2).is_err());
+    assert!(validate_sets(&idents(&["fnq"]), 1).is_ok());
+    assert!(validate_sets(&idents(&["enw"]), 3).is_err());
+    assert!(validate_sets(&idents(&["rwo"]), 2).is_ok());
+    assert!(validate_sets(&idents(&["xyz", "rwo"]), 3).is_err());
+}
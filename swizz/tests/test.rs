@@ -30,5 +30,68 @@ fn swizz() {
     let t = Vec4([0.0, 1.0, 2.0, 3.0]);
     let v2 = Vec2([1.0, 2.0]);
 
-    panic!("{:?}", v2.xxyx().0);
-}
\ No newline at end of file
+    assert_eq!(v2.xxyx().0, [1.0, 1.0, 2.0, 1.0]);
+    assert_eq!(t.wzy().0, [3.0, 2.0, 1.0]);
+    assert_eq!(t.x(), 0.0);
+}
+
+#[test]
+fn setters() {
+    let mut t = Vec4([0.0, 1.0, 2.0, 3.0]);
+
+    t.set_zx(Vec2([4.0, 5.0]));
+    assert_eq!(t.0, [5.0, 1.0, 4.0, 3.0]);
+
+    t.set_wzyx(Vec4([6.0, 7.0, 8.0, 9.0]));
+    assert_eq!(t.0, [9.0, 8.0, 7.0, 6.0]);
+
+    t.set_y(1.0);
+    *t.w_mut() += 1.0;
+    assert_eq!(t.0, [9.0, 1.0, 7.0, 7.0]);
+
+    let mut v3 = Vec3([0.0, 1.0, 2.0]);
+    v3.set_zyx(Vec3([3.0, 4.0, 5.0]));
+    assert_eq!(v3.0, [5.0, 4.0, 3.0]);
+}
+
+struct Color([f32; 4]);
+
+generate_swizzles!(Color, [xyzw, rgba, stpq], 2);
+
+#[test]
+fn multiple_sets() {
+    let c = Color([0.0, 1.0, 2.0, 3.0]);
+
+    assert_eq!(c.y(), c.g());
+    assert_eq!(c.g(), c.t());
+    assert_eq!(c.qs().0, [3.0, 0.0]);
+    assert_eq!(c.ar().0, c.wx().0);
+}
+
+struct DVec2([f64; 2]);
+struct DVec3([f64; 3]);
+
+impl DVec2 {
+    pub fn _new(x: f64, y: f64) -> Self {
+        Self([x, y])
+    }
+}
+impl DVec3 {
+    pub fn _new(x: f64, y: f64, z: f64) -> Self {
+        Self([x, y, z])
+    }
+}
+
+generate_swizzles!(DVec3, xyz, 3, f64, DVec);
+
+#[test]
+fn element_type() {
+    let mut d = DVec3([0.5, 1.5, 2.5]);
+    let z: f64 = d.z();
+
+    assert_eq!(z, 2.5);
+    assert_eq!(d.zx().0, [2.5, 0.5]);
+
+    d.set_yz(DVec2([3.0, 4.0]));
+    assert_eq!(d.zzy().0, [4.0, 4.0, 3.0]);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "cgmath")]
+
+use cgmath::Transform;
+use gl_types::{ivec2, mat3, mat4, uvec4, vec3, vec4, matrices::{Mat3, Mat4}, vectors::{IVec2, UVec4, Vec3, Vec4}};
+
+#[test]
+fn vectors() {
+    assert_eq!(cgmath::Vector4::from(vec4!(1, 2, 3, 4)), cgmath::Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Vec4::from(cgmath::Vector4::new(1.0, 2.0, 3.0, 4.0)), vec4!(1, 2, 3, 4));
+    assert_eq!(IVec2::from(cgmath::Vector2::new(-1, 2)), ivec2!(-1, 2));
+    assert_eq!(UVec4::from(cgmath::Vector4::from(uvec4!(1, 2, 3, 4))), uvec4!(1, 2, 3, 4));
+}
+
+#[test]
+fn matrices() {
+    let m = mat3!(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    let c = cgmath::Matrix3::from(m);
+    assert_eq!(c, cgmath::Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0));
+    assert_eq!(Mat3::from(c), m);
+
+    let translation = cgmath::Matrix4::from_translation(cgmath::Vector3::new(5.0, 6.0, 7.0));
+    let m = Mat4::from(translation);
+    assert_eq!(m, mat4!(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 7, 1));
+    assert_eq!(cgmath::Matrix4::from(m), translation);
+}
+
+#[test]
+fn points() {
+    // cgmath translates points but not vectors, so the conversion decides which one a Vec3 is
+    let translation = cgmath::Matrix4::from(mat4!(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 7, 1));
+    let p = translation.transform_point(cgmath::Point3::from(vec3!(1, 2, 3)));
+    assert_eq!(Vec3::from(p), vec3!(6, 8, 10));
+
+    let v = translation.transform_vector(cgmath::Vector3::from(vec3!(1, 2, 3)));
+    assert_eq!(Vec3::from(v), vec3!(1, 2, 3));
+}
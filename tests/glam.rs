@@ -0,0 +1,32 @@
+#![cfg(feature = "glam")]
+
+use gl_types::{ivec4, mat2, mat4, uvec3, vec3, matrices::{Mat2, Mat4}, vectors::{IVec4, UVec3, Vec3}};
+
+#[test]
+fn vectors() {
+    assert_eq!(glam::Vec3::from(vec3!(1, 2, 3)), glam::Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vec3::from(glam::Vec3::new(1.0, 2.0, 3.0)), vec3!(1, 2, 3));
+    assert_eq!(IVec4::from(glam::IVec4::new(-1, 2, -3, 4)), ivec4!(-1, 2, -3, 4));
+    assert_eq!(UVec3::from(glam::UVec3::from(uvec3!(7, 8, 9))), uvec3!(7, 8, 9));
+}
+
+#[test]
+fn aligned_vectors() {
+    let a = glam::Vec3A::from(vec3!(1, 0, 0));
+    assert_eq!(a, glam::Vec3A::X);
+    assert_eq!(Vec3::from(a.cross(glam::Vec3A::Y)), vec3!(0, 0, 1));
+}
+
+#[test]
+fn matrices() {
+    let m = mat2!(1, 2, 3, 4);
+    let g = glam::Mat2::from(m);
+    assert_eq!(g, glam::Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(Mat2::from(g), m);
+
+    let translation = glam::Mat4::from_translation(glam::Vec3::new(5.0, 6.0, 7.0));
+    let m = Mat4::from(translation);
+    assert_eq!(m, mat4!(1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 5, 6, 7, 1));
+    assert_eq!(glam::Mat4::from(m), translation);
+    assert_eq!(Vec3::from(glam::Mat4::from(m).transform_point3(vec3!(1, 2, 3).into())), vec3!(6, 8, 10));
+}
@@ -1,4 +1,4 @@
-use gl_types::{functions::geometric::{length, normalize}, mat2, mat3, mat4, matrix::inverse, vec2, vec3, vec4, vectors::{Vec2, Vec3, Vec4, VecN}};
+use gl_types::{assert_approx_eq, functions::epsilon::{epsilonEqual, epsilonNotEqual}, ApproxEq, Tolerance, functions::{clip_space::{frustum, ortho, perspective}, transform::lookAt, geometric::{length, normalize, try_normalize}, hash::*, integer::*, noise::*, sampling::*}, ivec2, ivec3, ivec4, mat2, mat3, mat4, matrix::{inverse, try_inverse}, GlTypesError, uvec2, uvec3, uvec4, vec2, vec3, vec4, matrices::{Mat2, Mat3, Mat4, MatN}, vectors::{swizzles::{Swizzle, SwizzleError, Swizzled}, IVec2, IVec3, UVec2, Vec2, Vec3, Vec4, VecN}};
 use rand::Rng;
 
 const TEST_COUNT: usize = 100000;
@@ -7,8 +7,8 @@ fn rand_array<const N: usize>() -> [f32; N] {
     let mut rng = rand::thread_rng();
 
     let mut array = [0f32; N];
-    for i in 0..N {
-        array[i] = rng.gen();
+    for el in array.iter_mut() {
+        *el = rng.gen();
     }
 
     array
@@ -233,6 +233,8 @@ pub fn multiplication() {
     }
 }
 
+// `rand_array` can return 0, and `nan-checks` panics on the resulting infinity
+#[cfg(not(feature = "nan-checks"))]
 #[test]
 pub fn division() {
     for _ in 0..TEST_COUNT {
@@ -304,7 +306,7 @@ pub fn length_test() {
 pub fn geom_test() {
     let v = vec3!(2, 0, 0);
 
-    assert_eq!(normalize(v), vec3!(1, 0, 0));
+    assert_approx_eq!(normalize(v), vec3!(1, 0, 0));
 }
 
 #[test]
@@ -312,7 +314,737 @@ fn inverse_test() {
     let mat = mat3!(vec3!(1, 4, 7), vec3!(2, 5, 2), vec3!(3, 6, 9));
     let inverse = inverse(mat);
 
-    assert_eq!(inverse, mat3!(vec3!(-11.0 / 12.0, - 1.0 / 6.0, 3.0 / 4.0), vec3!(1.0 / 3.0, 1.0 / 3.0, -1.0 / 3.0), vec3!(1.0 / 12.0, -1.0 / 6.0, 1.0 / 12.0)));
+    assert_approx_eq!(inverse, mat3!(vec3!(-11.0 / 12.0, - 1.0 / 6.0, 3.0 / 4.0), vec3!(1.0 / 3.0, 1.0 / 3.0, -1.0 / 3.0), vec3!(1.0 / 12.0, -1.0 / 6.0, 1.0 / 12.0)));
+}
+
+// Need more tests, but i'm lazy
+
+#[test]
+fn integer_test() {
+    let mut carry = UVec2::ZERO;
+    let sum = uaddCarry(uvec2!(u32::MAX, 1), uvec2!(2, 2), &mut carry);
+    assert_eq!(sum, uvec2!(1, 3));
+    assert_eq!(carry, uvec2!(1, 0));
+
+    let mut borrow = 0u32;
+    assert_eq!(usubBorrow(1u32, 2u32, &mut borrow), u32::MAX);
+    assert_eq!(borrow, 1);
+
+    let (mut msb, mut lsb) = (0u32, 0u32);
+    umulExtended(u32::MAX, u32::MAX, &mut msb, &mut lsb);
+    assert_eq!((msb, lsb), (0xFFFF_FFFE, 1));
+
+    let (mut msb, mut lsb) = (IVec2::ZERO, IVec2::ZERO);
+    imulExtended(ivec2!(-1, i32::MIN), ivec2!(1, 2), &mut msb, &mut lsb);
+    assert_eq!(msb, ivec2!(-1, -1));
+    assert_eq!(lsb, ivec2!(-1, 0));
+
+    assert_eq!(bitfieldExtract(0b1011_0000u32, 4, 4), 0b1011);
+    assert_eq!(bitfieldExtract(0b1011_0000i32, 4, 4), -5);
+    assert_eq!(bitfieldExtract(-1i32, 0, 32), -1);
+    assert_eq!(bitfieldExtract(u32::MAX, 8, 0), 0);
+    assert_eq!(bitfieldInsert(0xFFFF_FFFFu32, 0, 8, 8), 0xFFFF_00FF);
+    assert_eq!(bitfieldInsert(0u32, 0xABCD, 0, 32), 0xABCD);
+    assert_eq!(bitfieldReverse(1u32), 0x8000_0000);
+
+    assert_eq!(bitCount(uvec4!(0, 1, 3, u32::MAX)), ivec4!(0, 1, 2, 32));
+    assert_eq!(bitCount(-1i32), 32);
+
+    assert_eq!(findLSB(ivec3!(0, 8, -1)), ivec3!(-1, 3, 0));
+    assert_eq!(findMSB(0), -1);
+    assert_eq!(findMSB(-1), -1);
+    assert_eq!(findMSB(ivec3!(1, i32::MAX, -2)), ivec3!(0, 30, 0));
+    assert_eq!(findMSB(uvec2!(0, u32::MAX)), ivec2!(-1, 31));
+}
+
+#[test]
+fn integer_arithmetic_wraps() {
+    assert_eq!(uvec2!(u32::MAX, 0) + 1u32, uvec2!(0, 1));
+    assert_eq!(0u32 - uvec2!(1, 0), uvec2!(u32::MAX, 0));
+    assert_eq!(ivec2!(i32::MAX, 3) * 2, ivec2!(-2, 6));
+    assert_eq!(ivec2!(-8, 7) >> 1, ivec2!(-4, 3));
+    assert_eq!(uvec2!(0b1100, 0b1010) ^ uvec2!(0b1010, 0b1010), uvec2!(0b0110, 0));
+    assert_eq!(ivec2!(7, -7) % 3, ivec2!(1, -1));
+
+    // GLSL leaves division by zero undefined; here it gives 0 rather than panicking
+    assert_eq!(ivec2!(1, 2) / 0, ivec2!(0, 0));
+    assert_eq!(ivec2!(7, 8) / ivec2!(0, 2), ivec2!(0, 4));
+    assert_eq!(uvec2!(7, 8) % uvec2!(3, 0), uvec2!(1, 0));
+    assert_eq!(5u32 / uvec2!(0, 5), uvec2!(0, 1));
+    assert_eq!(ivec2!(i32::MIN, 1) / -1, ivec2!(i32::MIN, -1));
+    assert_eq!(ivec2!(vec2!(1.5, -2.5)), ivec2!(1, -2));
+}
+
+#[test]
+fn noise_test() {
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..1000 {
+        let v2 = rand_vec::<Vec2, 2>() * 100.0 - 50.0;
+        let v3 = rand_vec::<Vec3, 3>() * 100.0 - 50.0;
+        let v4 = rand_vec::<Vec4, 4>() * 100.0 - 50.0;
+
+        for n in [snoise(v2), snoise(v3), snoise(v4), cnoise(v2), cnoise(v3), cnoise(v4)] {
+            assert!((-1.25..=1.25).contains(&n), "{n} is out of range");
+        }
+
+        // Periodic variants repeat with their period
+        let rep = rng.gen_range(1..8) as f32;
+        assert!((pnoise(v2, vec2!(rep)) - pnoise(v2 + rep, vec2!(rep))).abs() < 1e-3);
+        assert!((pnoise(v3, vec3!(rep)) - pnoise(v3 + rep, vec3!(rep))).abs() < 1e-3);
+        assert!((pnoise(v4, vec4!(rep)) - pnoise(v4 + rep, vec4!(rep))).abs() < 1e-3);
+
+        let mut g = Vec2::ZERO;
+        let a = psrdnoise(v2, vec2!(rep), 0.5, &mut g);
+        let b = psrdnoise(v2 + vec2!(rep, 0.0), vec2!(rep), 0.5, &mut g);
+        assert!((a - b).abs() < 1e-3);
+
+        let mut g = Vec3::ZERO;
+        let a = psrdnoise(v3, vec3!(rep), 0.5, &mut g);
+        let b = psrdnoise(v3 + vec3!(0.0, rep, 0.0), vec3!(rep), 0.5, &mut g);
+        assert!((a - b).abs() < 1e-3);
+
+        let f = cellular(v2);
+        assert!(f.x() <= f.y());
+        let f = cellular(v3);
+        assert!(f.x() <= f.y());
+    }
+
+    // Classic Perlin noise is zero on the integer lattice
+    assert_eq!(cnoise(vec3!(3, -7, 12)), 0.0);
+
+    let octaves = fbm(vec2!(0.3, 0.7), 4, 2.0, 0.5, snoise::<Vec2>);
+    let expected = snoise(vec2!(0.3, 0.7)) + 0.5 * snoise(vec2!(0.6, 1.4)) + 0.25 * snoise(vec2!(1.2, 2.8)) + 0.125 * snoise(vec2!(2.4, 5.6));
+    assert!((octaves - expected).abs() < 1e-6);
+}
+
+#[test]
+fn noise_reference_values() {
+    // Evaluated with the reference GLSL (webgl-noise after its 2022 update, and psrdnoise), at points where float
+    // rounding can't flip a floor or step and so change which gradient is picked
+    #[track_caller]
+    fn close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    close(snoise(vec2!(1.44, -5.37)), 0.600696);
+    close(snoise(vec2!(-17.68, 0.3)), -0.219473);
+    close(snoise(vec2!(3.08, -4.13)), -0.168967);
+    close(snoise(vec3!(2.16, 1.58, -3.59)), 0.186458);
+    close(snoise(vec3!(2.16, 1.8, -3.62)), 0.142968);
+    close(snoise(vec3!(0.15, -14.12, 4.68)), 0.032724);
+    close(snoise(vec4!(3.43, 6.5, -0.59, -11.35)), -0.693914);
+    close(snoise(vec4!(10.31, -17.66, 11.55, -7.48)), -0.007791);
+    close(snoise(vec4!(0.92, 17.94, 2.13, -15.1)), -0.380635);
+
+    close(cnoise(vec2!(1.44, -5.37)), -0.25008);
+    close(cnoise(vec2!(-17.68, 0.3)), 0.67857);
+    close(cnoise(vec2!(3.08, -4.13)), -0.026128);
+    close(cnoise(vec3!(2.16, 1.58, -3.59)), -0.317328);
+    close(cnoise(vec3!(2.16, 1.8, -3.62)), -0.169089);
+    close(cnoise(vec3!(0.15, -14.12, 4.68)), 0.031495);
+    close(cnoise(vec4!(3.43, 6.5, -0.59, -11.35)), 0.220109);
+    close(cnoise(vec4!(10.31, -17.66, 11.55, -7.48)), 0.112137);
+    close(cnoise(vec4!(0.92, 17.94, 2.13, -15.1)), 0.002408);
+
+    close(pnoise(vec2!(1.44, -5.37), vec2!(4.0, 3.0)), 0.15956);
+    close(pnoise(vec2!(-17.68, 0.3), vec2!(4.0, 3.0)), 0.276927);
+    close(pnoise(vec2!(3.08, -4.13), vec2!(4.0, 3.0)), 0.052933);
+    close(pnoise(vec3!(2.16, 1.58, -3.59), vec3!(4.0, 3.0, 5.0)), 0.279196);
+    close(pnoise(vec3!(2.16, 1.8, -3.62), vec3!(4.0, 3.0, 5.0)), -0.162161);
+    close(pnoise(vec3!(0.15, -14.12, 4.68), vec3!(4.0, 3.0, 5.0)), 0.342956);
+    close(pnoise(vec4!(3.43, 6.5, -0.59, -11.35), vec4!(4.0, 3.0, 5.0, 2.0)), -0.307957);
+    close(pnoise(vec4!(10.31, -17.66, 11.55, -7.48), vec4!(4.0, 3.0, 5.0, 2.0)), 0.053553);
+    close(pnoise(vec4!(0.92, 17.94, 2.13, -15.1), vec4!(4.0, 3.0, 5.0, 2.0)), 0.010424);
+
+    let mut g2 = Vec2::ZERO;
+    close(psrdnoise(vec2!(1.44, -5.37), vec2!(0.0, 0.0), 0.0, &mut g2), -0.083957);
+    close(g2.x(), 2.255256);
+    close(g2.y(), -0.071555);
+    close(psrdnoise(vec2!(1.44, -5.37), vec2!(4.0, 3.0), 0.75, &mut g2), 0.788132);
+    close(g2.x(), -0.122338);
+    close(g2.y(), 1.050295);
+    close(psrdnoise(vec2!(-17.68, 0.3), vec2!(0.0, 0.0), 0.0, &mut g2), -0.197999);
+    close(g2.x(), -1.3264);
+    close(g2.y(), 1.09428);
+    close(psrdnoise(vec2!(-17.68, 0.3), vec2!(4.0, 3.0), 0.75, &mut g2), -0.131046);
+    close(g2.x(), -0.743631);
+    close(g2.y(), 0.373028);
+    close(psrdnoise(vec2!(3.08, -4.13), vec2!(0.0, 0.0), 0.0, &mut g2), -0.061587);
+    close(g2.x(), -3.521543);
+    close(g2.y(), -1.807052);
+    close(psrdnoise(vec2!(3.08, -4.13), vec2!(4.0, 3.0), 0.75, &mut g2), -0.5964);
+    close(g2.x(), -0.97263);
+    close(g2.y(), 2.888152);
+
+    let mut g3 = Vec3::ZERO;
+    close(psrdnoise(vec3!(2.16, 1.58, -3.59), vec3!(0.0, 0.0, 0.0), 0.0, &mut g3), 0.382704);
+    close(g3.x(), 0.07399);
+    close(g3.y(), 0.488385);
+    close(g3.z(), 1.243135);
+    close(psrdnoise(vec3!(2.16, 1.58, -3.59), vec3!(4.0, 3.0, 5.0), 0.75, &mut g3), 0.362857);
+    close(g3.x(), 0.666275);
+    close(g3.y(), -1.45151);
+    close(g3.z(), -0.387537);
+    close(psrdnoise(vec3!(2.16, 1.8, -3.62), vec3!(0.0, 0.0, 0.0), 0.0, &mut g3), 0.532143);
+    close(g3.x(), -0.938942);
+    close(g3.y(), 0.505808);
+    close(g3.z(), -1.195524);
+    close(psrdnoise(vec3!(2.16, 1.8, -3.62), vec3!(4.0, 3.0, 5.0), 0.75, &mut g3), 0.318711);
+    close(g3.x(), -1.138109);
+    close(g3.y(), 0.914773);
+    close(g3.z(), -1.609326);
+    close(psrdnoise(vec3!(0.15, -14.12, 4.68), vec3!(0.0, 0.0, 0.0), 0.0, &mut g3), -0.443299);
+    close(g3.x(), 2.21967);
+    close(g3.y(), -0.27121);
+    close(g3.z(), -1.272345);
+    close(psrdnoise(vec3!(0.15, -14.12, 4.68), vec3!(4.0, 3.0, 5.0), 0.75, &mut g3), -0.308883);
+    close(g3.x(), 2.655447);
+    close(g3.y(), -0.861311);
+    close(g3.z(), -1.488503);
+
+    let f = cellular(vec2!(1.44, -5.37));
+    close(f.x(), 0.379164);
+    close(f.y(), 0.67256);
+    let f = cellular(vec2!(-17.68, 0.3));
+    close(f.x(), 0.517995);
+    close(f.y(), 0.753598);
+    let f = cellular(vec2!(3.08, -4.13));
+    close(f.x(), 0.711877);
+    close(f.y(), 0.867693);
+    let f = cellular(vec3!(2.16, 1.58, -3.59));
+    close(f.x(), 0.41429);
+    close(f.y(), 0.507565);
+    let f = cellular(vec3!(2.16, 1.8, -3.62));
+    close(f.x(), 0.559037);
+    close(f.y(), 0.626299);
+    let f = cellular(vec3!(0.15, -14.12, 4.68));
+    close(f.x(), 0.577161);
+    close(f.y(), 0.629804);
+}
+
+#[test]
+fn hash_test() {
+    // Reference values computed with 32-bit wrapping arithmetic, as on the GPU.
+    assert_eq!(pcg(0u32), 129708002);
+    assert_eq!(pcg(uvec2!(1, 123456789)), uvec2!(2831084092u32, 4272394698u32));
+    assert_eq!(pcg2d(uvec2!(1, 2)), uvec2!(45825804, 214070181));
+    assert_eq!(pcg3d(uvec3!(1, 2, 3)), uvec3!(4204755366u32, 1223881804, 1500469937));
+    assert_eq!(xxhash32(0u32), 878055299);
+    assert_eq!(xxhash32(42u32), 2046153942);
+    assert_eq!(xxhash32(uvec3!(1, 2, 3)), 243197822);
+    assert_eq!(wang_hash(uvec2!(0, 7)), uvec2!(3232319850u32, 851741419));
+    assert_eq!(murmur3_fmix32(1u32), 0x514e28b7);
+    assert_eq!(murmur3_fmix32(0xdeadbeefu32), 233162409);
+
+    assert_eq!(unit_float(0u32), 0.0);
+    assert_eq!(unit_float(u32::MAX), 1.0 - f32::EPSILON);
+    assert_eq!(unit_float(uvec2!(0x8000_0000u32, 0)), vec2!(0.5, 0.0));
+
+    for i in 0..1000u32 {
+        let v = unit_float(pcg4d(uvec4!(i, i + 1, i + 2, i + 3)));
+        assert!(v.as_array().iter().all(|c| (0.0..1.0).contains(c)));
+    }
+}
+
+fn transform(m: Mat4, v: Vec4) -> Vec4 {
+    let m = m.as_array();
+    let v = v.as_array();
+
+    Vec4::from_array(std::array::from_fn(|r| (0..4).map(|c| m[c][r] * v[c]).sum()))
+}
+
+#[test]
+fn sampling_test() {
+    assert_eq!(halton23(0), vec2!(0.0));
+    assert_eq!(halton23(1), vec2!(0.5, 1.0 / 3.0));
+    assert_eq!(halton23(2), vec2!(0.25, 2.0 / 3.0));
+    assert_eq!(halton23(3), vec2!(0.75, 1.0 / 9.0));
+    assert_eq!(halton(5, 5), 0.04);
+
+    assert_eq!(sobol2(0), vec2!(0.0));
+    assert_eq!(sobol2(1), vec2!(0.5, 0.5));
+    assert_eq!(sobol2(2), vec2!(0.25, 0.75));
+    assert_eq!(sobol2(3), vec2!(0.75, 0.25));
+
+    assert_eq!(r2(0), vec2!(0.5));
+
+    for i in 0..4096 {
+        for p in [halton23(i), r2(i), sobol2(i)] {
+            assert!(p.as_array().iter().all(|c| (0.0..1.0).contains(c)));
+        }
+    }
+
+    // The first 2^k Sobol points have exactly one point in each 1/2^k wide column and row.
+    let mut columns = [false; 64];
+    let mut rows = [false; 64];
+    for i in 0..64 {
+        let p = sobol2(i) * 64.0;
+        columns[p.x() as usize] = true;
+        rows[p.y() as usize] = true;
+    }
+    assert!(columns.iter().all(|c| *c) && rows.iter().all(|r| *r));
+
+    let resolution = vec2!(1920, 1080);
+    let jitter = vec2!(0.25, -0.5);
+    for projection in [perspective(1.0, 16.0 / 9.0, 0.1, 100.0), ortho(-2.0, 3.0, -1.0, 1.0, 0.1, 10.0)] {
+        assert_eq!(jitter_projection(projection, vec2!(0.0), resolution), projection);
+
+        let jittered = jitter_projection(projection, jitter, resolution);
+        for point in [vec4!(0.3, -0.2, -1.5, 1.0), vec4!(-1.0, 0.7, -5.0, 1.0)] {
+            let a = transform(projection, point);
+            let b = transform(jittered, point);
+            let shift = vec2!(b.x() / b.w() - a.x() / a.w(), b.y() / b.w() - a.y() / a.w()) * resolution / 2.0;
+
+            assert!(length(shift - jitter) < 1e-3);
+            assert_eq!(b.z(), a.z());
+            assert_eq!(b.w(), a.w());
+        }
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut uniform_z = 0.0;
+    let mut cosine_z = 0.0;
+    for i in 0..TEST_COUNT as u32 {
+        let u = vec2!(rng.gen::<f32>(), rng.gen::<f32>());
+        let a = uniform_hemisphere(u);
+        let b = cosine_hemisphere(u);
+
+        assert!((length(a) - 1.0).abs() < 1e-5 && a.z() >= 0.0);
+        assert!((length(b) - 1.0).abs() < 1e-5 && b.z() >= 0.0);
+
+        let q = halton23(i + 1);
+        uniform_z += uniform_hemisphere(q).z();
+        cosine_z += cosine_hemisphere(q).z();
+    }
+
+    // E[cos θ] is 1/2 for uniform and 2/3 for cosine-weighted hemisphere sampling.
+    assert!((uniform_z / TEST_COUNT as f32 - 0.5).abs() < 1e-3);
+    assert!((cosine_z / TEST_COUNT as f32 - 2.0 / 3.0).abs() < 1e-3);
+}
+
+#[test]
+fn sampling_upper_bound() {
+    // Near the end of the index range the sequences come within half an ulp of 1, which must not round up to it
+    assert_eq!(halton23(u32::MAX).x(), 1.0 - f32::EPSILON / 2.0);
+    for i in u32::MAX - 4096..=u32::MAX {
+        for p in [halton23(i), r2(i), sobol2(i), vec2!(halton(i, 5), halton(i, 7))] {
+            assert!(p.as_array().iter().all(|c| (0.0..1.0).contains(c)), "{i}: {p:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "swizzles")]
+fn swizzle_setters() {
+    let mut v = vec4!(1, 2, 3, 4);
+
+    v.set_xy(vec2!(5, 6));
+    assert_eq!(v, vec4!(5, 6, 3, 4));
+
+    v.set_bgr(vec3!(7, 8, 9));
+    assert_eq!(v, vec4!(9, 8, 7, 4));
+
+    *v.a_mut() = 0.0;
+    v.set_y(1.0);
+    assert_eq!(v, vec4!(9, 1, 7, 0));
+
+    v.set_qp(vec2!(2, 3));
+    assert_eq!(v.stpq(), vec4!(9, 1, 3, 2));
+    assert_eq!(vec2!(4, 5).ts(), vec2!(5, 4));
+}
+
+#[test]
+#[cfg(feature = "swizzles")]
+fn integer_swizzles() {
+    let mut v = ivec4!(1, -2, 3, -4);
+
+    assert_eq!(v.wzyx(), ivec4!(-4, 3, -2, 1));
+    assert_eq!(v.xx(), ivec2!(1, 1));
+    assert_eq!(v.b(), 3);
+
+    v.set_zw(ivec2!(7, 8));
+    assert_eq!(v, ivec4!(1, -2, 7, 8));
+
+    let mut u = uvec3!(1, 2, 3);
+    *u.p_mut() += 1;
+    assert_eq!(u.zyx(), uvec3!(4, 2, 1));
+    assert_eq!(u.stpp(), uvec4!(1, 2, 4, 4));
+}
+
+#[test]
+fn index_swizzles() {
+    let v = vec4!(1, 2, 3, 4);
+
+    assert_eq!(v.swizzle([2, 1, 0]), vec3!(3, 2, 1));
+    assert_eq!(v.swizzle([3, 3]), vec2!(4, 4));
+    assert_eq!(v.swizzle([1]), 2.0);
+    assert_eq!(ivec2!(5, -6).swizzle([1, 0, 1, 0]), ivec4!(-6, 5, -6, 5));
+    assert_eq!(uvec3!(7, 8, 9).swizzle([0, 2]), uvec2!(7, 9));
+
+    #[cfg(feature = "swizzles")]
+    {
+        assert_eq!(v.swizzle([3, 2, 1, 0]), v.wzyx());
+        assert_eq!(v.swizzle([0, 2]), v.rb());
+    }
+}
+
+#[test]
+fn runtime_swizzles() {
+    let v = vec4!(1, 2, 3, 4);
+
+    assert_eq!(v.swizzle_str("bgra"), Ok(Swizzled::Vec4(vec4!(3, 2, 1, 4))));
+    assert_eq!(v.swizzle_str("xzy"), Ok(Swizzled::Vec3(v.swizzle([0, 2, 1]))));
+    assert_eq!(v.swizzle_str("qq"), Ok(Swizzled::Vec2(vec2!(4, 4))));
+    assert_eq!(v.swizzle_str("w"), Ok(Swizzled::Scalar(4.0)));
+    assert_eq!(v.swizzle_dyn(&[2, 1, 0]), Ok(Swizzled::Vec3(vec3!(3, 2, 1))));
+
+    assert_eq!(v.swizzle_str(""), Err(SwizzleError::InvalidLength(0)));
+    assert_eq!(v.swizzle_str("xyzwx"), Err(SwizzleError::InvalidLength(5)));
+    assert_eq!(v.swizzle_str("xk"), Err(SwizzleError::InvalidComponent('k')));
+    assert_eq!(v.swizzle_str("xg"), Err(SwizzleError::MixedSets));
+    assert_eq!(vec2!(1, 2).swizzle_str("xz"), Err(SwizzleError::OutOfRange(2)));
+    assert_eq!(vec3!(1, 2, 3).swizzle_dyn(&[0, 3]), Err(SwizzleError::OutOfRange(3)));
+
+    assert_eq!(ivec3!(1, -2, 3).swizzle_str("zyx"), Ok(Swizzled::Vec3(ivec3!(3, -2, 1))));
+    assert_eq!(ivec2!(1, -2).swizzle_dyn(&[1]), Ok(Swizzled::Scalar(-2)));
+    assert_eq!(uvec4!(1, 2, 3, 4).swizzle_str("ar"), Ok(Swizzled::Vec2(uvec2!(4, 1))));
+    assert_eq!(uvec2!(1, 2).swizzle_str("xyz"), Err(SwizzleError::OutOfRange(2)));
+}
+
+#[test]
+#[should_panic]
+fn swizzle_out_of_range() {
+    vec2!(1, 2).swizzle([0, 2]);
+}
+
+#[test]
+fn indexing() {
+    let mut v = vec3!(1, 2, 3);
+    assert_eq!(v[0], 1.0);
+    assert_eq!(v[2], 3.0);
+
+    v[1] = 5.0;
+    v[2] += 1.0;
+    assert_eq!(v, vec3!(1, 5, 4));
+
+    let mut i = ivec4!(1, 2, 3, 4);
+    i[3] = -1;
+    assert_eq!(i[3], -1);
+    assert_eq!(uvec2!(7, 8)[1], 8);
+
+    // Columns are contiguous in as_array, so m[c][r] == as_array()[c][r]
+    let mut m = Mat4::from_array([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0], [9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]]);
+    assert_eq!(m[1], vec4!(5, 6, 7, 8));
+    assert_eq!(m[3][2], 15.0);
+    assert_eq!(m.column(0), vec4!(1, 2, 3, 4));
+    assert_eq!(m.row(0), vec4!(1, 5, 9, 13));
+    assert_eq!(m.row(3), vec4!(4, 8, 12, 16));
+
+    m[2][0] = 0.0;
+    m[0] = vec4!(-1);
+    assert_eq!(m.as_array()[2][0], 0.0);
+    assert_eq!(m.as_array()[0], [-1.0; 4]);
+
+    m.set_row(1, vec4!(20, 21, 22, 23));
+    assert_eq!(m.row(1), vec4!(20, 21, 22, 23));
+    assert_eq!(m[3], vec4!(13, 23, 15, 16));
+
+    m.set_column(3, vec4!(0));
+    assert_eq!(m[3], vec4!(0));
+
+    let mut m2 = Mat2::from_array([[1.0, 2.0], [3.0, 4.0]]);
+    m2[1][0] = 9.0;
+    assert_eq!(m2.row(0), vec2!(1, 9));
+
+    let m3 = Mat3::from_array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(m3[2], vec3!(7, 8, 9));
+    assert_eq!(m3.row(2), vec3!(3, 6, 9));
 }
 
-// Need more tests, but i'm lazy
\ No newline at end of file
+#[test]
+fn column_major_constructors() {
+    // As in GLSL, the first N scalars are the first column
+    let m = mat3!(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    assert_eq!(m[0], vec3!(1, 2, 3));
+    assert_eq!(m.row(0), vec3!(1, 4, 7));
+    assert_eq!(m.as_array(), [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    assert_eq!(m, mat3!(vec3!(1, 2, 3), vec3!(4, 5, 6), vec3!(7, 8, 9)));
+
+    assert_eq!(mat2!(1, 2, 3, 4)[1], vec2!(3, 4));
+    assert_eq!(mat4!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16)[3], vec4!(13, 14, 15, 16));
+
+    assert_eq!(Mat3::from_cols(vec3!(1, 2, 3), vec3!(4, 5, 6), vec3!(7, 8, 9)), m);
+    assert_eq!(Mat3::from_rows(vec3!(1, 4, 7), vec3!(2, 5, 8), vec3!(3, 6, 9)), m);
+    assert_eq!(Mat2::from_rows(vec2!(1, 3), vec2!(2, 4)), mat2!(1, 2, 3, 4));
+    assert_eq!(Mat4::from_rows(vec4!(1, 0, 0, 5), vec4!(0, 1, 0, 6), vec4!(0, 0, 1, 7), vec4!(0, 0, 0, 1))[3], vec4!(5, 6, 7, 1));
+}
+
+fn assert_close(a: Vec4, b: Vec4) {
+    assert!(length(a - b) < 1e-4, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn clip_space_and_view() {
+    // Reference values from glm::perspective (right handed, depth in [-1, 1])
+    let (fovy, aspect, near, far) = (1.0f32, 16.0 / 9.0, 0.1, 100.0);
+    let p = perspective(fovy, aspect, near, far);
+    let f = 1.0 / (fovy / 2.0).tan();
+    assert_close(p[0], vec4!(f / aspect, 0, 0, 0));
+    assert_close(p[1], vec4!(0, f, 0, 0));
+    assert_close(p[2], vec4!(0, 0, -(far + near) / (far - near), -1));
+    assert_close(p[3], vec4!(0, 0, -2.0 * far * near / (far - near), 0));
+
+    let project = |m: Mat4, v: Vec4| {
+        let c = transform(m, v);
+        c / c.w()
+    };
+
+    // The corners of the view volume map to the corners of the NDC cube
+    let m = frustum(-1.0, 2.0, -3.0, 4.0, 0.5, 10.0);
+    assert_close(project(m, vec4!(-1, -3, -0.5, 1)), vec4!(-1, -1, -1, 1));
+    assert_close(project(m, vec4!(2, 4, -0.5, 1)), vec4!(1, 1, -1, 1));
+    assert_close(project(m, vec4!(40, 80, -10, 1)), vec4!(1, 1, 1, 1));
+
+    let m = ortho(-1.0, 2.0, -3.0, 4.0, 0.5, 10.0);
+    assert_close(project(m, vec4!(-1, -3, -0.5, 1)), vec4!(-1, -1, -1, 1));
+    assert_close(project(m, vec4!(2, 4, -10, 1)), vec4!(1, 1, 1, 1));
+
+    let view = lookAt(vec3!(0, 0, 5), vec3!(0), vec3!(0, 1, 0));
+    assert_close(view[3], vec4!(0, 0, -5, 1));
+    assert_close(view[0], vec4!(1, 0, 0, 0));
+
+    // The camera looks down -z, with +x to its right
+    let view = lookAt(vec3!(1, 2, 3), vec3!(4, 2, 3), vec3!(0, 1, 0));
+    assert_close(transform(view, vec4!(4, 2, 3, 1)), vec4!(0, 0, -3, 1));
+    assert_close(transform(view, vec4!(1, 2, 5, 1)), vec4!(2, 0, 0, 1));
+    assert_close(transform(view, vec4!(1, 7, 3, 1)), vec4!(0, 5, 0, 1));
+}
+
+#[test]
+fn mixed_matrix_constructors() {
+    let m = mat3!(vec2!(1, 2), 3, vec4!(4, 5, 6, 7), 8.0f64, 9u32);
+    assert_eq!(m, mat3!(1, 2, 3, 4, 5, 6, 7, 8, 9));
+
+    // The last argument may be partially used
+    let m = mat2!(1, vec4!(2, 3, 4, 5));
+    assert_eq!(m, mat2!(1, 2, 3, 4));
+
+    let m = mat4!(vec3!(1, 2, 3), ivec4!(4, 5, 6, 7), uvec2!(8, 9), 10, vec3!(11, 12, 13), vec4!(14, 15, 16, 17));
+    assert_eq!(m[3], vec4!(13, 14, 15, 16));
+    assert_eq!(m[1], vec4!(5, 6, 7, 8));
+
+    assert_eq!(mat4!(vec4!(1), vec4!(2), vec4!(3), vec4!(4))[2], vec4!(3));
+    assert_eq!(mat3!(2), mat3!(vec3!(2)));
+    assert_eq!(mat2!(1.5f64), mat2!(1.5, 0, 0, 1.5));
+
+    // Resizing keeps the top left corner
+    let big = mat4!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+    assert_eq!(mat3!(big), mat3!(1, 2, 3, 5, 6, 7, 9, 10, 11));
+    assert_eq!(mat2!(big), mat2!(1, 2, 5, 6));
+    assert_eq!(mat2!(mat3!(big)), mat2!(1, 2, 5, 6));
+
+    // A matrix of the same size is copied
+    assert_eq!(mat4!(big), big);
+    assert_eq!(mat3!(mat3!(big)), mat3!(big));
+    assert_eq!(mat2!(mat2!(1)), mat2!(1, 0, 0, 1));
+}
+
+#[test]
+fn vector_constructors() {
+    let v2 = vec2!(1, 2);
+    let v3 = vec3!(3, 4, 5);
+    let v4 = vec4!(6, 7, 8, 9);
+
+    assert_eq!(vec4!(v3, v2), vec4!(3, 4, 5, 1));
+    assert_eq!(vec4!(1, v2, 3.0f64), vec4!(1, 1, 2, 3));
+    assert_eq!(vec4!(v2, v2), vec4!(1, 2, 1, 2));
+    assert_eq!(vec4!(0, 0, v4), vec4!(0, 0, 6, 7));
+    assert_eq!(vec3!(1.0, 2.0, v4), vec3!(1, 2, 6));
+    assert_eq!(vec3!(v4), vec3!(6, 7, 8));
+    assert_eq!(vec2!(v3), vec2!(3, 4));
+    assert_eq!(vec2!(v2,), v2);
+
+    // Vectors of other element types are converted
+    assert_eq!(vec4!(ivec2!(-1, 2), uvec3!(3, 4, 5)), vec4!(-1, 2, 3, 4));
+    assert_eq!(ivec3!(vec2!(1.5, -2.5), 7u32), ivec3!(1, -2, 7));
+    assert_eq!(uvec2!(ivec4!(1, 2, 3, 4)), uvec2!(1, 2));
+    assert_eq!(ivec4!(uvec2!(1, 2), vec3!(3, 4, 5)), ivec4!(1, 2, 3, 4));
+    assert_eq!(vec3!(ivec3!(1, 2, 3)), vec3!(1, 2, 3));
+
+    // Single smaller vectors are still padded with zeros
+    assert_eq!(vec4!(v2), vec4!(1, 2, 0, 0));
+}
+
+const RED: Vec4 = vec4!(1.0, 0.0, 0.0, 1.0);
+const GREY: Vec3 = vec3!(0.5);
+const DOWN: IVec3 = ivec3!(0, -1, 0);
+const WRAPPED: UVec2 = uvec2!(4294967295, 2u32);
+static CALIBRATION: Mat3 = mat3!(2, 0, 0, 0, 2, 0, 0.5, -0.5, 1);
+const SCALE: Mat4 = mat4!(3);
+const EMPTY: Mat2 = mat2!();
+const COLUMNS: Mat2 = Mat2::from_cols_const(Vec2::new_const(1.0, 2.0), Vec2::new_const(3.0, 4.0));
+
+#[test]
+fn const_constructors() {
+    assert_eq!(RED, vec4!(vec3!(1, 0, 0), 1));
+    assert_eq!(GREY, Vec3::new_const(0.5, 0.5, 0.5));
+    assert_eq!(DOWN, IVec3::new_const(0, -1, 0));
+    assert_eq!(WRAPPED, uvec2!(u32::MAX, 2));
+    assert_eq!(CALIBRATION, mat3!(vec3!(2, 0, 0), vec3!(0, 2, 0), vec3!(0.5, -0.5, 1)));
+    assert_eq!(SCALE, mat4!(3.0f64));
+    assert_eq!(EMPTY, Mat2::ZERO);
+    assert_eq!(COLUMNS, mat2!(1, 2, 3, 4));
+    assert_eq!(Mat4::from_cols_const(RED, RED, RED, RED), Mat4::from_cols(RED, RED, RED, RED));
+    assert_eq!(Mat3::new_const(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0)[1], vec3!(4, 5, 6));
+
+    // Arguments that aren't all literals still go through the constructor traits
+    let x = 2.0f32;
+    assert_eq!(vec4!(0, -x, -(x + 1.0), 1.0), vec4!(0, -2, -3, 1));
+    assert_eq!(vec3!(-x), vec3!(-2));
+    assert_eq!(vec3!(1.0f32.max(x), 1, 1), vec3!(2, 1, 1));
+    assert_eq!(uvec2!(-1, 2), WRAPPED);
+}
+
+#[test]
+fn fallible_api() {
+    let m = mat3!(2, 0, 0, 0, 4, 0, 1, 2, 1);
+    assert_eq!(try_inverse(m), Ok(inverse(m)));
+    assert_eq!(try_inverse(mat2!(1, 2, 2, 4)), Err(GlTypesError::SingularMatrix));
+    assert_eq!(try_inverse(Mat4::ZERO), Err(GlTypesError::SingularMatrix));
+    assert_eq!(try_inverse(mat2!(1e-39, 0, 0, 1)), Err(GlTypesError::SingularMatrix));
+    #[cfg(not(feature = "nan-checks"))]
+    {
+        assert_eq!(try_inverse(mat2!(f32::NAN, 0, 0, 1)), Err(GlTypesError::NonFiniteMatrix));
+        assert_eq!(try_inverse(mat2!(f32::INFINITY, 0, 0, 1)), Err(GlTypesError::NonFiniteMatrix));
+    }
+
+    assert_eq!(try_normalize(vec3!(0, 3, 4)), Ok(vec3!(0, 0.6, 0.8)));
+    assert_eq!(try_normalize(Vec2::ZERO), Err(GlTypesError::ZeroLengthVector));
+    #[cfg(not(feature = "nan-checks"))]
+    assert_eq!(try_normalize(vec4!(f32::NAN)), Err(GlTypesError::NonFiniteVector));
+    #[cfg(not(feature = "nan-checks"))]
+    assert_eq!(try_normalize(vec2!(f32::INFINITY, 0)), Err(GlTypesError::NonFiniteVector));
+    assert_approx_eq!(try_normalize(vec3!(1e30)).unwrap(), vec3!(1.0 / 3f32.sqrt()));
+    assert_approx_eq!(try_normalize(vec2!(0, -1e-30)).unwrap(), vec2!(0, -1));
+
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(Vec3::try_from(&data[1..4]), Ok(vec3!(2, 3, 4)));
+    assert_eq!(Vec4::try_from(&data[..]), Err(GlTypesError::WrongLength { expected: 4, found: 5 }));
+    assert_eq!(Mat2::try_from(&data[..4]), Ok(mat2!(1, 2, 3, 4)));
+    assert_eq!(Mat3::try_from(&data[..]), Err(GlTypesError::WrongLength { expected: 9, found: 5 }));
+    assert_eq!(IVec2::try_from(&[-1, 7][..]), Ok(ivec2!(-1, 7)));
+    assert_eq!(UVec2::try_from(&[1u32][..]), Err(GlTypesError::WrongLength { expected: 2, found: 1 }));
+
+    assert_eq!(GlTypesError::WrongLength { expected: 4, found: 5 }.to_string(), "expected 4 components, found 5");
+}
+
+#[test]
+fn approximate_equality() {
+    let a = vec3!(1.0, 100.0, 0.0);
+    let b = vec3!(1.0 + 1e-6, 100.0 + 1e-4, 1e-7);
+
+    assert!(a.approx_eq(&b));
+    assert!(!a.approx_eq_abs(&b, 1e-5));
+    assert!(a.approx_eq_abs(&b, 1e-3));
+    assert!(a.approx_eq_relative(&b, 1e-6, 2e-6));
+    assert!(!a.approx_eq_relative(&b, 1e-6, 1e-7));
+    assert!(1.0f32.approx_eq_ulps(&(1.0 + f32::EPSILON * 2.0), 0.0, 2));
+    assert!(!1.0f32.approx_eq_ulps(&(1.0 + f32::EPSILON * 3.0), 0.0, 2));
+    assert!(!(-0.5f32).approx_eq_ulps(&0.5, 0.0, u32::MAX));
+    assert!(!f32::NAN.approx_eq(&f32::NAN));
+    assert!(f32::INFINITY.approx_eq(&f32::INFINITY));
+
+    assert_eq!(epsilonEqual(a, b, 1e-5), [true, false, true]);
+    assert_eq!(epsilonNotEqual(a, b, 1e-5), [false, true, false]);
+
+    let rotation = mat2!(0.6, 0.8, -0.8, 0.6);
+    assert!(rotation.is_orthogonal(Tolerance::DEFAULT));
+    assert!(rotation.is_normalized(Tolerance::DEFAULT));
+    assert!(!rotation.is_identity(Tolerance::DEFAULT));
+    assert!(mat2!(1.0 + 1e-7, 0, 0, 1).is_identity(Tolerance::Ulps { epsilon: 0.0, max_ulps: 4 }));
+    assert!(!mat2!(2, 0, 0, 0.5).is_orthogonal(Tolerance::DEFAULT));
+    assert!(normalize(vec4!(1, 2, 3, 4)).is_normalized(Tolerance::Absolute(1e-6)));
+
+    let message = std::panic::catch_unwind(|| assert_approx_eq!(mat2!(1, 2, 3, 4), mat2!(1, 2, 3.5, 4), Tolerance::Absolute(0.1)))
+        .expect_err("matrices differ").downcast_ref::<String>().cloned().unwrap();
+    assert!(message.contains("Absolute(0.1)"), "{}", message);
+    assert!(message.contains("[1][0]: 3.0 vs 3.5 (difference 0.5)"), "{}", message);
+    assert!(!message.contains("[0][0]"), "{}", message);
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn approx_crate() {
+    let a = mat3!(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let b = a + 1e-7;
+
+    approx::assert_relative_eq!(a, b);
+    approx::assert_ulps_eq!(vec2!(1.0, 2.0), vec2!(1.0, 2.0 + f32::EPSILON));
+    approx::assert_abs_diff_ne!(vec3!(1.0), vec3!(1.1), epsilon = 0.05);
+}
+
+#[test]
+fn glsl_literals() {
+    assert_eq!(vec3!(1, 2.5, -3).to_string(), "vec3(1.0, 2.5, -3.0)");
+    assert_eq!(format!("{:.2}", vec2!(1, 1.0 / 3.0)), "vec2(1.00, 0.33)");
+    assert_eq!(ivec3!(1, -2, 3).to_string(), "ivec3(1, -2, 3)");
+    assert_eq!(uvec2!(1, 2).to_string(), "uvec2(1u, 2u)");
+    assert_eq!(mat2!(1, 2, 3, 4).to_string(), "mat2(1.0, 2.0, 3.0, 4.0)");
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+        let v = Vec4::from_array(rng.gen()) * 1000.0 - 500.0;
+        assert_eq!(v.to_string().parse::<Vec4>(), Ok(v));
+
+        let m = Mat4::from_array(rng.gen()) * 1e-6;
+        assert_eq!(m.to_string().parse::<Mat4>(), Ok(m));
+    }
+
+    assert_eq!("vec4(vec2(1.0, 2.0), 3, 4.0f)".parse(), Ok(vec4!(1, 2, 3, 4)));
+    assert_eq!(" vec3( 0.5 ) ".parse(), Ok(vec3!(0.5)));
+    assert_eq!("vec3(vec4(1.0, 2.0, 3.0, 4.0))".parse(), Ok(vec3!(1, 2, 3)));
+    assert_eq!("vec3(1.0, vec4(2.0, 3.0, 4.0, 5.0))".parse(), Ok(vec3!(1, 2, 3)));
+    assert_eq!("uvec2(1u, 2U)".parse(), Ok(uvec2!(1, 2)));
+    assert_eq!("ivec2(-1, +2)".parse(), Ok(ivec2!(-1, 2)));
+    assert_eq!("mat2(2.0)".parse(), Ok(mat2!(2)));
+    assert_eq!("mat2(vec2(1.0, 2.0), vec2(3.0, 4.0))".parse(), Ok(mat2!(1, 2, 3, 4)));
+    assert_eq!("mat3(mat2(1.0, 2.0, 3.0, 4.0))".parse(), Ok(mat3!(1, 2, 0, 3, 4, 0, 0, 0, 1)));
+    assert_eq!("mat2(mat3(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0))".parse(), Ok(mat2!(1, 2, 4, 5)));
+    assert_eq!("uvec2(-1, 2)".parse(), Ok(uvec2!(u32::MAX, 2)));
+    assert_eq!("ivec2(3000000000, 1)".parse(), Ok(ivec2!(3000000000u32 as i32, 1)));
+    assert_eq!("ivec2(-2.75, 1e3)".parse(), Ok(ivec2!(-2, 1000)));
+    assert_eq!(uvec2!(u32::MAX, 2).to_string().parse(), Ok(uvec2!(u32::MAX, 2)));
+    assert_eq!(ivec2!(i32::MIN, i32::MAX).to_string().parse(), Ok(ivec2!(i32::MIN, i32::MAX)));
+
+    assert_eq!("vec3(1.0, 2.0)".parse::<Vec3>(), Err(GlTypesError::WrongLength { expected: 3, found: 2 }));
+    assert_eq!("vec2(1.0, 2.0, 3.0)".parse::<Vec2>(), Err(GlTypesError::WrongLength { expected: 2, found: 3 }));
+    assert_eq!("vec3(1.0, 2.0, 3.0)".parse::<Vec4>(), Err(GlTypesError::WrongType { expected: "vec4" }));
+    assert_eq!("vec3(1.0, 2.0,)".parse::<Vec3>(), Err(GlTypesError::InvalidSyntax { position: 14 }));
+    assert_eq!("vec3(1.0, 2.0, 3.0) x".parse::<Vec3>(), Err(GlTypesError::InvalidSyntax { position: 20 }));
+    assert_eq!("vec3(1.0, 2.0, 3.0x)".parse::<Vec3>(), Err(GlTypesError::InvalidSyntax { position: 18 }));
+    assert_eq!("foo(1.0)".parse::<Vec3>(), Err(GlTypesError::InvalidSyntax { position: 0 }));
+    assert_eq!("vec2(1e40, 0)".parse::<Vec2>(), Err(GlTypesError::InvalidSyntax { position: 5 }));
+    assert_eq!("ivec2(1e40, 0)".parse::<IVec2>(), Err(GlTypesError::InvalidSyntax { position: 6 }));
+    assert_eq!("ivec2(99999999999999999999, 0)".parse::<IVec2>(), Err(GlTypesError::InvalidSyntax { position: 6 }));
+}
+
+#[test]
+fn nalgebra_conversions() {
+    let v = vec3!(1, 2, 3);
+    let n: nalgebra::Vector3<f32> = v.into();
+    assert_eq!(n, nalgebra::Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vec3::from(n), v);
+    assert_eq!(v.as_nalgebra().norm(), length(v));
+    assert_eq!(IVec2::from(nalgebra::Vector2::new(1, -2)), ivec2!(1, -2));
+
+    let m = mat2!(1, 2, 3, 4);
+    let n = nalgebra::Matrix2::from(m);
+    assert_eq!(n, nalgebra::Matrix2::from_columns(&[nalgebra::Vector2::new(1.0, 2.0), nalgebra::Vector2::new(3.0, 4.0)]));
+    assert_eq!(Mat2::from(n), m);
+    assert_eq!(mat4!(2).as_nalgebra().try_inverse().map(Mat4::from), Some(mat4!(0.5)));
+
+    let mut v = vec2!(3, 4);
+    v.as_nalgebra_mut().normalize_mut();
+    assert_eq!(v, vec2!(0.6, 0.8));
+}
@@ -0,0 +1,65 @@
+use std::mem::{align_of, size_of};
+
+use gl_types::{as_bytes, as_flat_slice, as_flat_slice_mut, from_flat_slice, from_flat_slice_mut, mat2, mat4, uvec2, vec3, GlTypesError, matrices::{Mat2, Mat3, Mat4}, vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4}};
+
+#[test]
+fn layout() {
+    assert_eq!((size_of::<Vec2>(), align_of::<Vec2>()), (8, 4));
+    assert_eq!((size_of::<Vec3>(), align_of::<Vec3>()), (12, 4));
+    assert_eq!((size_of::<Vec4>(), align_of::<Vec4>()), (16, 4));
+    assert_eq!((size_of::<IVec2>(), size_of::<IVec3>(), size_of::<IVec4>()), (8, 12, 16));
+    assert_eq!((size_of::<UVec2>(), size_of::<UVec3>(), size_of::<UVec4>()), (8, 12, 16));
+    assert_eq!((size_of::<Mat2>(), align_of::<Mat2>()), (16, 4));
+    assert_eq!((size_of::<Mat3>(), align_of::<Mat3>()), (36, 4));
+    assert_eq!((size_of::<Mat4>(), align_of::<Mat4>()), (64, 4));
+
+    // Arrays are tightly packed, with no padding between elements
+    assert_eq!(size_of::<[Vec3; 5]>(), 60);
+}
+
+#[test]
+fn flat_slices() {
+    let points = [vec3!(1, 2, 3), vec3!(4, 5, 6)];
+    assert_eq!(as_flat_slice(&points), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    // Matrices are flattened column by column
+    assert_eq!(as_flat_slice(&[mat2!(1, 2, 3, 4)]), &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(as_flat_slice(&[mat4!(1)])[..5], [1.0, 0.0, 0.0, 0.0, 0.0]);
+
+    assert_eq!(as_flat_slice(&[uvec2!(7, 8)]), &[7u32, 8]);
+
+    let flat = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    assert_eq!(from_flat_slice::<Vec3>(&flat), Ok(&points[..]));
+    assert_eq!(from_flat_slice::<Vec2>(&flat).map(<[_]>::len), Ok(3));
+    assert_eq!(from_flat_slice::<Vec4>(&flat), Err(GlTypesError::NotAMultiple { components: 4, found: 6 }));
+    assert_eq!(from_flat_slice::<Mat4>(&[]), Ok(&[][..]));
+
+    let mut points = points;
+    as_flat_slice_mut(&mut points)[4] = 10.0;
+    assert_eq!(points[1], vec3!(4, 10, 6));
+
+    let mut flat = flat;
+    from_flat_slice_mut::<Vec2>(&mut flat).unwrap()[0] = Vec2::ZERO;
+    assert_eq!(flat[..2], [0.0, 0.0]);
+}
+
+#[test]
+fn bytes() {
+    let points = [vec3!(1, 2, 3)];
+    let bytes = as_bytes(&points);
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(bytes[4..8], 2.0f32.to_ne_bytes());
+
+    assert_eq!(as_bytes(&[Mat3::IDENTITY]).len(), 36);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck() {
+    let points = [vec3!(1, 2, 3), vec3!(4, 5, 6)];
+    assert_eq!(bytemuck::cast_slice::<Vec3, f32>(&points), as_flat_slice(&points));
+    assert_eq!(bytemuck::cast_slice::<Vec3, u8>(&points), as_bytes(&points));
+    assert_eq!(bytemuck::cast::<[f32; 4], Mat2>([1.0, 2.0, 3.0, 4.0]), mat2!(1, 2, 3, 4));
+    assert_eq!(<Mat4 as bytemuck::Zeroable>::zeroed(), Mat4::ZERO);
+    assert_eq!(<IVec3 as bytemuck::Zeroable>::zeroed(), IVec3::ZERO);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "mint")]
+
+use gl_types::{ivec3, mat2, mat3, uvec2, vec2, vec4, matrices::{Mat2, Mat3}, vectors::{IVec3, UVec2, Vec2, Vec4}};
+
+#[test]
+fn vectors() {
+    let v: mint::Vector2<f32> = vec2!(1, 2).into();
+    assert_eq!(v, mint::Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(Vec2::from(v), vec2!(1, 2));
+
+    let v = mint::Vector4::from([1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(Vec4::from(v), vec4!(1, 2, 3, 4));
+
+    assert_eq!(IVec3::from(mint::Vector3 { x: -1, y: 0, z: 1 }), ivec3!(-1, 0, 1));
+    assert_eq!(mint::Vector2::from(uvec2!(3, 4)), mint::Vector2 { x: 3u32, y: 4 });
+    assert_eq!(UVec2::from(mint::Vector2 { x: 3u32, y: 4 }), uvec2!(3, 4));
+}
+
+#[test]
+fn matrices() {
+    let m = mat2!(1, 2, 3, 4);
+
+    let columns = mint::ColumnMatrix2::from(m);
+    assert_eq!(columns, mint::ColumnMatrix2::from([[1.0, 2.0], [3.0, 4.0]]));
+    assert_eq!(Mat2::from(columns), m);
+
+    // mint's row matrices store rows, so converting transposes the storage but not the matrix
+    let rows = mint::RowMatrix2::from(m);
+    assert_eq!(rows, mint::RowMatrix2::from([[1.0, 3.0], [2.0, 4.0]]));
+    assert_eq!(Mat2::from(rows), m);
+    assert_eq!(mint::RowMatrix2::from(columns), rows);
+
+    let m = mat3!(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    assert_eq!(mint::RowMatrix3::from(m), mint::RowMatrix3::from([[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]]));
+    assert_eq!(Mat3::from(mint::RowMatrix3::from(m)), m);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "nan-checks")]
+
+use std::panic::catch_unwind;
+
+use gl_types::{functions::{geometric::normalize, matrix::{inverse, try_inverse}}, mat2, vec2, vec3, vec4, vectors::{Vec3, VecN}, GlTypesError};
+
+fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = catch_unwind(f).expect_err("expected a non-finite check to fail");
+
+    // The const constructors panic with a plain `&str`, since they can't format
+    payload.downcast_ref::<String>().cloned().or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string())).unwrap_or_default()
+}
+
+#[test]
+fn reports_operation_and_operands() {
+    let message = panic_message(|| { let _ = vec2!(1, 2) / 0.0; });
+    assert_eq!(message, "Vec2 / scalar produced a non-finite result [inf, inf] from operands [[1.0, 2.0], [0.0]]");
+
+    let message = panic_message(|| { let _ = vec3!(0, 1, 2) / vec3!(0, 1, 1); });
+    assert!(message.starts_with("Vec3 / Vec3 produced a non-finite result [NaN, 1.0, 2.0]"), "{}", message);
+
+    let message = panic_message(|| { let _ = normalize(Vec3::ZERO); });
+    assert_eq!(message, "normalize produced a non-finite result [NaN, NaN, NaN] from operands [[0.0, 0.0, 0.0]]");
+
+    let message = panic_message(|| { let _ = inverse(mat2!(1, 2, 2, 4)); });
+    assert!(message.starts_with("inverse produced a non-finite result"), "{}", message);
+}
+
+#[test]
+fn constructors() {
+    let message = panic_message(|| { let _ = vec4!(1.0, f64::NAN, 2, 3); });
+    assert_eq!(message, "Vec4 constructor produced a non-finite result [1.0, NaN, 2.0, 3.0]");
+
+    let message = panic_message(|| { let _ = vec2!(f64::MAX); });
+    assert_eq!(message, "Vec2 constructor produced a non-finite result [inf, inf]");
+
+    let message = panic_message(|| { let _ = mat2!(vec2!(1, 2), f32::INFINITY, 0); });
+    assert!(message.starts_with("Mat2 constructor"), "{}", message);
+
+    let message = panic_message(|| { let _ = Vec3::from_array([0.0, f32::NAN, 0.0]); });
+    assert!(message.starts_with("VecN::from_array"), "{}", message);
+
+    // Literal arguments take the const path, which overflows to infinity when narrowed to f32
+    let message = panic_message(|| { let _ = vec2!(1e40f64, 0); });
+    assert_eq!(message, "Vec2::new_const produced a non-finite result");
+
+    let message = panic_message(|| { let _ = mat2!(-1e40f64); });
+    assert_eq!(message, "Mat2::new_const produced a non-finite result");
+}
+
+#[test]
+fn finite_values_pass() {
+    let v = normalize(vec3!(0, 0, 4)) * 2.0 - vec3!(1);
+    assert_eq!(v, vec3!(-1, -1, 1));
+
+    // The fallible API reports the problem without tripping the checks
+    assert_eq!(try_inverse(mat2!(1, 2, 2, 4)), Err(GlTypesError::SingularMatrix));
+}
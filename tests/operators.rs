@@ -0,0 +1,314 @@
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use gl_types::{ivec3, matrices::{Mat2, Mat3, Mat4, MatN}, uvec2, vectors::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4, VecN}};
+use rand::Rng;
+
+const TEST_COUNT: usize = 1000;
+
+trait Components: Copy {
+    fn components(self) -> Vec<f32>;
+    fn random(rng: &mut impl Rng) -> Self;
+}
+
+macro_rules! components {
+    ($t:ty, vector) => {
+        impl Components for $t {
+            fn components(self) -> Vec<f32> {
+                self.as_array().to_vec()
+            }
+
+            fn random(rng: &mut impl Rng) -> Self {
+                <$t>::from_array(std::array::from_fn(|_| nonzero(rng)))
+            }
+        }
+    };
+    ($t:ty, matrix) => {
+        impl Components for $t {
+            fn components(self) -> Vec<f32> {
+                self.as_array().concat()
+            }
+
+            fn random(rng: &mut impl Rng) -> Self {
+                <$t>::from_array(std::array::from_fn(|_| std::array::from_fn(|_| nonzero(rng))))
+            }
+        }
+    };
+}
+
+components!(Vec2, vector);
+components!(Vec3, vector);
+components!(Vec4, vector);
+components!(Mat2, matrix);
+components!(Mat3, matrix);
+components!(Mat4, matrix);
+
+fn nonzero(rng: &mut impl Rng) -> f32 {
+    let x: f32 = rng.gen_range(0.5..10.0);
+
+    if rng.gen() { x } else { -x }
+}
+
+fn zip(a: impl Components, b: impl Components, f: impl Fn(f32, f32) -> f32) -> Vec<f32> {
+    a.components().into_iter().zip(b.components()).map(|(a, b)| f(a, b)).collect()
+}
+
+fn map(a: impl Components, f: impl Fn(f32) -> f32) -> Vec<f32> {
+    a.components().into_iter().map(f).collect()
+}
+
+macro_rules! float_operator {
+    ($t:ty, $op:tt, $assign:tt) => {{
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..TEST_COUNT {
+            let a = <$t>::random(&mut rng);
+            let b = <$t>::random(&mut rng);
+            let expected = zip(a, b, |x, y| x $op y);
+
+            assert_eq!((a $op b).components(), expected);
+            assert_eq!((&a $op b).components(), expected);
+            assert_eq!((a $op &b).components(), expected);
+            assert_eq!((&a $op &b).components(), expected);
+
+            let mut c = a;
+            c $assign b;
+            assert_eq!(c.components(), expected);
+
+            let mut c = a;
+            c $assign &b;
+            assert_eq!(c.components(), expected);
+        }
+
+        float_operator!(@scalar $t, $op, $assign);
+    }};
+    (@scalar $t:ty, $op:tt, $assign:tt) => {{
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..TEST_COUNT {
+            let a = <$t>::random(&mut rng);
+            let s = nonzero(&mut rng);
+
+            assert_eq!((a $op s).components(), map(a, |x| x $op s));
+            assert_eq!((&a $op s).components(), map(a, |x| x $op s));
+            assert_eq!((s $op a).components(), map(a, |x| s $op x));
+            assert_eq!((s $op &a).components(), map(a, |x| s $op x));
+
+            // Other scalar types are converted to f32 first
+            assert_eq!((a $op 3i32).components(), map(a, |x| x $op 3.0));
+            assert_eq!((a $op 3u64).components(), map(a, |x| x $op 3.0));
+            assert_eq!((3i32 $op a).components(), map(a, |x| 3.0 $op x));
+            assert_eq!((3u32 $op a).components(), map(a, |x| 3.0 $op x));
+            assert_eq!((-3i64 $op a).components(), map(a, |x| -3.0 $op x));
+            assert_eq!((2.5f64 $op a).components(), map(a, |x| 2.5 $op x));
+
+            let mut c = a;
+            c $assign s;
+            assert_eq!(c.components(), map(a, |x| x $op s));
+
+            let mut c = a;
+            c $assign 2u32;
+            assert_eq!(c.components(), map(a, |x| x $op 2.0));
+        }
+    }};
+}
+
+/// The column-major components of `a * b`, summed in f64, for `n` by `n` matrices and `n` component vectors.
+fn product(a: &[f32], b: &[f32], n: usize) -> Vec<f32> {
+    let columns = b.len() / n;
+
+    (0..columns).flat_map(|j| (0..n).map(move |i| (0..n).map(|k| a[k * n + i] as f64 * b[j * n + k] as f64).sum::<f64>() as f32)).collect()
+}
+
+#[track_caller]
+fn assert_close(actual: Vec<f32>, expected: Vec<f32>) {
+    assert!(actual.iter().zip(&expected).all(|(a, e)| (a - e).abs() <= 1e-4 * e.abs().max(1.0)), "{actual:?} != {expected:?}");
+}
+
+macro_rules! matrix_product {
+    ($t:ty, $v:ty, $n:literal) => {{
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..TEST_COUNT {
+            let a = <$t>::random(&mut rng);
+            let b = <$t>::random(&mut rng);
+            let v = <$v>::random(&mut rng);
+
+            let ab = a * b;
+            assert_close(ab.components(), product(&a.components(), &b.components(), $n));
+            assert_eq!(&a * b, ab);
+            assert_eq!(a * &b, ab);
+            assert_eq!(&a * &b, ab);
+
+            let mut c = a;
+            c *= b;
+            assert_eq!(c, ab);
+
+            let mut c = a;
+            c *= &b;
+            assert_eq!(c, ab);
+
+            // A vector on the right is a column, and on the left a row, so `v * a` is `transpose(a) * v`
+            let av = a * v;
+            assert_close(av.components(), product(&a.components(), &v.components(), $n));
+            assert_eq!(&a * v, av);
+            assert_eq!(a * &v, av);
+            assert_eq!(&a * &v, av);
+
+            let va = v * a;
+            let transposed = <$t>::from_array(std::array::from_fn(|i| a.row(i).as_array()));
+            assert_close(va.components(), product(&transposed.components(), &v.components(), $n));
+            assert_eq!(&v * a, va);
+            assert_eq!(v * &a, va);
+            assert_eq!(&v * &a, va);
+
+            let mut w = v;
+            w *= a;
+            assert_eq!(w, va);
+
+            let mut w = v;
+            w *= &a;
+            assert_eq!(w, va);
+
+            assert_eq!(<$t>::IDENTITY * a, a);
+            assert_eq!(a * <$t>::IDENTITY, a);
+            assert_eq!(<$t>::IDENTITY * v, v);
+        }
+    }};
+}
+
+macro_rules! float_operators {
+    (@common $t:ty) => {
+        float_operator!($t, +, +=);
+        float_operator!($t, -, -=);
+        float_operator!($t, /, /=);
+        float_operator!($t, %, %=);
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..TEST_COUNT {
+            let a = <$t>::random(&mut rng);
+
+            assert_eq!((-a).components(), map(a, |x| -x));
+            assert_eq!((-&a).components(), map(a, |x| -x));
+        }
+    };
+    ($name:ident, $t:ty) => {
+        #[test]
+        fn $name() {
+            float_operator!($t, *, *=);
+            float_operators!(@common $t);
+        }
+    };
+    // `*` between matrices, and between a matrix and a vector, is the linear algebra product
+    ($name:ident, $t:ty, $v:ty, $n:literal) => {
+        #[test]
+        fn $name() {
+            float_operator!(@scalar $t, *, *=);
+            matrix_product!($t, $v, $n);
+            float_operators!(@common $t);
+        }
+    };
+}
+
+float_operators!(vec2_operators, Vec2);
+float_operators!(vec3_operators, Vec3);
+float_operators!(vec4_operators, Vec4);
+float_operators!(mat2_operators, Mat2, Vec2, 2);
+float_operators!(mat3_operators, Mat3, Vec3, 3);
+float_operators!(mat4_operators, Mat4, Vec4, 4);
+
+#[test]
+fn matrix_product_values() {
+    let m = Mat2::from_array([[1.0, 2.0], [3.0, 4.0]]);
+
+    assert_eq!(m * Mat2::from_array([[5.0, 6.0], [7.0, 8.0]]), Mat2::from_array([[23.0, 34.0], [31.0, 46.0]]));
+    assert_eq!(m * Vec2::from_array([1.0, 0.0]), Vec2::from_array([1.0, 2.0]));
+    assert_eq!(Vec2::from_array([1.0, 0.0]) * m, Vec2::from_array([1.0, 3.0]));
+}
+
+#[test]
+fn scalar_on_left() {
+    let v = Vec3::from_array([1.0, 2.0, 4.0]);
+
+    assert_eq!((2.0f32 - v).as_array(), [1.0, 0.0, -2.0]);
+    assert_eq!((2.0f32 * v).as_array(), [2.0, 4.0, 8.0]);
+    assert_eq!((2.0f32 / v).as_array(), [2.0, 1.0, 0.5]);
+    assert_eq!((5i32 % v).as_array(), [0.0, 1.0, 1.0]);
+    assert_eq!((-v).as_array(), [-1.0, -2.0, -4.0]);
+}
+
+macro_rules! integer_operator {
+    ($t:ty, $s:ty, $n:literal, $op:tt, $assign:tt, $f:ident) => {{
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..TEST_COUNT {
+            let x: [$s; $n] = rng.gen();
+            let y: [$s; $n] = std::array::from_fn(|_| rng.gen_range(1..100));
+            let s: $s = rng.gen_range(1..100);
+            let (a, b) = (<$t>::from_array(x), <$t>::from_array(y));
+            let expected = <$t>::from_array(std::array::from_fn(|i| x[i].$f(y[i])));
+
+            assert_eq!(a $op b, expected);
+            assert_eq!(&a $op b, expected);
+            assert_eq!(a $op &b, expected);
+            assert_eq!(&a $op &b, expected);
+
+            assert_eq!(a $op s, <$t>::from_array(x.map(|x| x.$f(s))));
+            assert_eq!(&a $op s, <$t>::from_array(x.map(|x| x.$f(s))));
+            assert_eq!(s $op b, <$t>::from_array(y.map(|y| s.$f(y))));
+            assert_eq!(s $op &b, <$t>::from_array(y.map(|y| s.$f(y))));
+
+            let mut c = a;
+            c $assign b;
+            assert_eq!(c, expected);
+
+            let mut c = a;
+            c $assign &b;
+            assert_eq!(c, expected);
+
+            let mut c = a;
+            c $assign s;
+            assert_eq!(c, <$t>::from_array(x.map(|x| x.$f(s))));
+        }
+    }};
+}
+
+macro_rules! integer_operators {
+    ($name:ident, $t:ty, $s:ty, $n:literal) => {
+        #[test]
+        fn $name() {
+            integer_operator!($t, $s, $n, +, +=, wrapping_add);
+            integer_operator!($t, $s, $n, -, -=, wrapping_sub);
+            integer_operator!($t, $s, $n, *, *=, wrapping_mul);
+            integer_operator!($t, $s, $n, /, /=, wrapping_div);
+            integer_operator!($t, $s, $n, %, %=, wrapping_rem);
+            integer_operator!($t, $s, $n, &, &=, bitand);
+            integer_operator!($t, $s, $n, |, |=, bitor);
+            integer_operator!($t, $s, $n, ^, ^=, bitxor);
+
+            let mut rng = rand::thread_rng();
+            for _ in 0..TEST_COUNT {
+                let x: [$s; $n] = rng.gen();
+                let a = <$t>::from_array(x);
+
+                assert_eq!(-a, <$t>::from_array(x.map(<$s>::wrapping_neg)));
+                assert_eq!(-&a, -a);
+                assert_eq!(!a, <$t>::from_array(x.map(|x| !x)));
+                assert_eq!(!&a, !a);
+            }
+        }
+    };
+}
+
+integer_operators!(ivec2_operators, IVec2, i32, 2);
+integer_operators!(ivec3_operators, IVec3, i32, 3);
+integer_operators!(ivec4_operators, IVec4, i32, 4);
+integer_operators!(uvec2_operators, UVec2, u32, 2);
+integer_operators!(uvec3_operators, UVec3, u32, 3);
+integer_operators!(uvec4_operators, UVec4, u32, 4);
+
+#[test]
+fn integer_scalar_on_left() {
+    assert_eq!(10 - ivec3!(1, 2, 3), ivec3!(9, 8, 7));
+    assert_eq!(12 / ivec3!(1, 2, 3), ivec3!(12, 6, 4));
+    assert_eq!(0u32 - uvec2!(1, 0), uvec2!(u32::MAX, 0));
+}
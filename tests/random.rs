@@ -0,0 +1,73 @@
+#![cfg(feature = "rand")]
+#![allow(non_snake_case)]
+
+use gl_types::{functions::{geometric::length, random::*}, vec3, vectors::{Vec2, Vec3, Vec4, VecN}, matrices::{Mat4, MatN}};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const TEST_COUNT: usize = 10000;
+
+#[test]
+fn distributions() {
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for _ in 0..TEST_COUNT {
+        let v: Vec4 = rng.gen();
+        assert!(v.as_array().iter().all(|c| (0.0..1.0).contains(c)));
+
+        let m: Mat4 = rng.gen();
+        assert!(m.as_array().iter().flatten().all(|c| (0.0..1.0).contains(c)));
+    }
+}
+
+#[test]
+fn linear_and_gauss() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let min = vec3!(-1, 10, 100);
+    let max = vec3!(1, 20, 200);
+    for _ in 0..TEST_COUNT {
+        let v = linearRand(min, max, &mut rng);
+        for ((c, lo), hi) in v.as_array().into_iter().zip(min.as_array()).zip(max.as_array()) {
+            assert!(lo <= c && c <= hi);
+        }
+
+        let s = linearRand(2.0, 3.0, &mut rng);
+        assert!((2.0..=3.0).contains(&s));
+    }
+
+    let mean = vec3!(1, -2, 5);
+    let deviation = vec3!(0.5, 1, 2);
+    let mut sum = Vec3::ZERO;
+    let mut sum_sq = Vec3::ZERO;
+    for _ in 0..TEST_COUNT {
+        let v = gaussRand(mean, deviation, &mut rng);
+        sum += v;
+        sum_sq += (v - mean) * (v - mean);
+    }
+
+    let n = TEST_COUNT as f32;
+    let sample_mean = sum / n;
+    let sample_deviation = Vec3::from_array((sum_sq / n).as_array().map(f32::sqrt));
+    for i in 0..3 {
+        assert!((sample_mean.as_array()[i] - mean.as_array()[i]).abs() < 0.1);
+        assert!((sample_deviation.as_array()[i] - deviation.as_array()[i]).abs() < 0.1);
+    }
+}
+
+#[test]
+fn geometric_sampling() {
+    let mut rng = StdRng::seed_from_u64(2);
+
+    for _ in 0..TEST_COUNT {
+        assert!((length(circularRand(2.0, &mut rng)) - 2.0).abs() < 1e-5);
+        assert!((length(sphericalRand(3.0, &mut rng)) - 3.0).abs() < 1e-5);
+        assert!(length(diskRand(2.0, &mut rng)) <= 2.0 + 1e-5);
+        assert!(length(ballRand(3.0, &mut rng)) <= 3.0 + 1e-5);
+    }
+
+    let mean = (0..TEST_COUNT).map(|_| sphericalRand(1.0, &mut rng)).fold(Vec3::ZERO, |a, b| a + b) / TEST_COUNT as f32;
+    assert!(length(mean) < 0.05);
+
+    let mean = (0..TEST_COUNT).map(|_| diskRand(1.0, &mut rng)).fold(Vec2::ZERO, |a, b| a + b) / TEST_COUNT as f32;
+    assert!(length(mean) < 0.05);
+}
@@ -0,0 +1,171 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{bracketed, parse::Parse, parse_macro_input, punctuated::Punctuated, token::Comma, Ident, LitInt, Type};
+
+/// `generate_constructors!(Vec4, Vec4Constructor, f32, 4, [Vec, IVec, UVec])`
+struct Input {
+    _type: Ident,
+    constructor: Ident,
+    scalar: Type,
+    len: LitInt,
+    families: Vec<Ident>
+}
+
+impl Parse for Input {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let _type = input.parse()?;
+        input.parse::<Comma>()?;
+        let constructor = input.parse()?;
+        input.parse::<Comma>()?;
+        let scalar = input.parse()?;
+        input.parse::<Comma>()?;
+        let len = input.parse()?;
+        input.parse::<Comma>()?;
+
+        let content;
+        bracketed!(content in input);
+        let families = Punctuated::<Ident, Comma>::parse_terminated(&content)?.into_iter().collect();
+
+        Ok(Input { _type, constructor, scalar, len, families })
+    }
+}
+
+/// One constructor argument: a scalar (size 1) or a vector from one of the families.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Arg {
+    Scalar,
+    Vector(usize, usize)
+}
+
+impl Arg {
+    fn size(self) -> usize {
+        match self {
+            Arg::Scalar => 1,
+            Arg::Vector(_, size) => size
+        }
+    }
+}
+
+/// Every argument list that fills `len` components, where each argument contributes at least one.
+///
+/// As in GLSL, the last argument may be only partially used. Single arguments are only included if they are
+/// vectors at least as large as the target; scalar splats are generated separately and zero-extension is written
+/// by hand.
+fn signatures(len: usize, families: usize) -> Vec<Vec<Arg>> {
+    let mut options = vec![Arg::Scalar];
+    for family in 0..families {
+        for size in 2..=4 {
+            options.push(Arg::Vector(family, size));
+        }
+    }
+
+    let mut complete = Vec::new();
+    let mut partial = vec![(Vec::new(), 0)];
+    while let Some((args, filled)) = partial.pop() {
+        for arg in &options {
+            let mut args: Vec<Arg> = args.clone();
+            args.push(*arg);
+
+            if filled + arg.size() >= len {
+                if args.len() > 1 || *arg != Arg::Scalar {
+                    complete.push(args);
+                }
+            } else {
+                partial.push((args, filled + arg.size()));
+            }
+        }
+    }
+
+    complete
+}
+
+#[proc_macro]
+pub fn generate_constructors(item: TokenStream) -> TokenStream {
+    let Input { _type, constructor, scalar, len, families } = parse_macro_input!(item as Input);
+    let len: usize = len.base10_parse().unwrap();
+    let name = format!("{} constructor", _type);
+
+    // Splats are implemented per scalar type rather than for `A: GLScalar`, so that a bad argument list is reported
+    // against the constructor trait instead of as a tuple that isn't a scalar.
+    let splats = ["i32", "i64", "u32", "u64", "f32", "f64"].map(|s| {
+        let s = format_ident!("{}", s);
+        let components = (0..len).map(|_| quote!(num::cast::AsPrimitive::<#scalar>::as_(args)));
+
+        quote! {
+            impl #constructor<#s> for #_type {
+                fn new(args: #s) -> #_type {
+                    let result = #_type::_new(#(#components),*);
+                    crate::check_finite!(#name, result.0);
+
+                    result
+                }
+            }
+        }
+    });
+
+    splats.into_iter().chain(signatures(len, families.len()).into_iter().map(|args| {
+        let generics: Vec<Ident> = args.iter().enumerate()
+            .filter(|(_, arg)| **arg == Arg::Scalar)
+            .map(|(i, _)| format_ident!("A{}", i))
+            .collect();
+
+        let types: Vec<proc_macro2::TokenStream> = args.iter().enumerate().map(|(i, arg)| match arg {
+            Arg::Scalar => {
+                let generic = format_ident!("A{}", i);
+                quote!(#generic)
+            },
+            Arg::Vector(family, size) => {
+                let vector = format_ident!("{}{}", families[*family], size);
+                quote!(crate::vectors::#vector)
+            }
+        }).collect();
+
+        let components: Vec<proc_macro2::TokenStream> = args.iter().enumerate().flat_map(|(i, arg)| {
+            let arg_tokens = if args.len() == 1 {
+                quote!(args)
+            } else {
+                let index = syn::Index::from(i);
+                quote!(args.#index)
+            };
+
+            match arg {
+                Arg::Scalar => vec![arg_tokens],
+                Arg::Vector(_, size) => (0..*size).map(|c| quote!(#arg_tokens[#c])).collect()
+            }
+        }).take(len).collect();
+
+        let signature = if args.len() == 1 {
+            types[0].clone()
+        } else {
+            quote!((#(#types,)*))
+        };
+
+        quote! {
+            impl<#(#generics: crate::GLScalar),*> #constructor<#signature> for #_type {
+                fn new(args: #signature) -> #_type {
+                    let result = #_type::_new(#(num::cast::AsPrimitive::<#scalar>::as_(#components)),*);
+                    crate::check_finite!(#name, result.0);
+
+                    result
+                }
+            }
+        }
+    })).collect::<proc_macro2::TokenStream>().into()
+}
+
+#[test]
+fn signature_counts() {
+    // vec2: (s, s), (s, v) for 3 sizes, (v) for 3 sizes
+    assert_eq!(signatures(2, 1).len(), 1 + 3 + 3);
+
+    let sigs = signatures(4, 1);
+    assert!(sigs.contains(&vec![Arg::Vector(0, 3), Arg::Vector(0, 2)]));
+    assert!(sigs.contains(&vec![Arg::Scalar, Arg::Scalar, Arg::Vector(0, 4)]));
+    assert!(!sigs.contains(&vec![Arg::Vector(0, 4), Arg::Scalar]));
+    assert!(!sigs.contains(&vec![Arg::Vector(0, 2)]));
+    assert!(!sigs.contains(&vec![Arg::Scalar]));
+    assert!(sigs.iter().all(|args| {
+        let before_last: usize = args[..args.len() - 1].iter().map(|a| a.size()).sum();
+        before_last < 4 && before_last + args[args.len() - 1].size() >= 4
+    }));
+}